@@ -0,0 +1,418 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::Point;
+
+/// Something that can be drawn in the terminal, a character per cell
+pub trait Render {
+    /// Everything there is to draw
+    fn bounds(&self) -> Bounds;
+
+    fn cell(&self, pos: Point<i64>) -> char;
+
+    /// Where the action is, for the viewport to stay around when the scene doesn't fit
+    fn focus(&self) -> Option<Point<i64>> {
+        None
+    }
+
+    /// A line of text shown under the scene
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+/// A scene that changes a step at a time
+pub trait Simulation: Render {
+    /// Move on to the next frame, or return false if the simulation has already finished
+    fn step(&mut self) -> bool;
+}
+
+/// A rectangle of cells from `min` to `max` inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point<i64>,
+    pub max: Point<i64>,
+}
+
+impl Bounds {
+    pub fn new(min: Point<i64>, max: Point<i64>) -> Self {
+        Bounds { min, max }
+    }
+
+    /// The smallest bounds containing every one of `points`, or `None` if there aren't any
+    pub fn around(points: impl IntoIterator<Item = Point<i64>>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, pos| {
+            Some(match bounds {
+                None => Bounds::new(pos, pos),
+                Some(Bounds { min, max }) => Bounds::new(
+                    Point::new(min.x.min(pos.x), min.y.min(pos.y)),
+                    Point::new(max.x.max(pos.x), max.y.max(pos.y)),
+                ),
+            })
+        })
+    }
+
+    /// The smallest bounds containing both of these
+    pub fn union(&self, other: &Bounds) -> Self {
+        Bounds::around([self.min, self.max, other.min, other.max]).unwrap()
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    /// The part of these bounds that fits in a `width` by `height` window: all of it if it
+    /// fits, otherwise a window centred on `focus` (or the middle) as far as the edges allow
+    pub fn crop(&self, focus: Option<Point<i64>>, width: usize, height: usize) -> Self {
+        let middle = Point::new((self.min.x + self.max.x) / 2, (self.min.y + self.max.y) / 2);
+        let focus = focus.unwrap_or(middle);
+        let (min_x, max_x) = crop_axis(self.min.x, self.max.x, focus.x, width);
+        let (min_y, max_y) = crop_axis(self.min.y, self.max.y, focus.y, height);
+        Bounds::new(Point::new(min_x, min_y), Point::new(max_x, max_y))
+    }
+}
+
+fn crop_axis(min: i64, max: i64, focus: i64, size: usize) -> (i64, i64) {
+    let size = size.max(1) as i64;
+    if max - min < size {
+        return (min, max);
+    }
+    let start = (focus - size / 2).clamp(min, max - size + 1);
+    (start, start + size - 1)
+}
+
+/// Draw the part of `scene` inside `view`, a line per row
+pub fn frame(scene: &(impl Render + ?Sized), view: Bounds) -> String {
+    let mut frame = String::with_capacity((view.width() + 1) * view.height());
+    for y in view.min.y..=view.max.y {
+        if y > view.min.y {
+            frame.push('\n');
+        }
+        for x in view.min.x..=view.max.x {
+            frame.push(scene.cell(Point::new(x, y)));
+        }
+    }
+    frame
+}
+
+/// How much of each frame is printed when headless, unless given a size. Some scenes are far too
+/// big to print whole.
+pub const HEADLESS_SIZE: (usize, usize) = (160, 48);
+
+/// Plays simulations in the terminal, or just prints each frame when headless
+#[derive(Debug, Clone)]
+pub struct Player {
+    fps: f64,
+    size: Option<(usize, usize)>,
+    headless: bool,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player {
+            fps: 10.0,
+            size: None,
+            headless: false,
+        }
+    }
+}
+
+impl Player {
+    pub fn new() -> Self {
+        Player::default()
+    }
+
+    /// How many frames to show a second when playing
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Crop every frame to `width` by `height`. Otherwise frames are cropped to fit the
+    /// terminal when playing, and to `HEADLESS_SIZE` when headless.
+    pub fn size(mut self, width: usize, height: usize) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Print every frame one after another as fast as they come, rather than playing them.
+    /// Output that isn't a terminal is always headless.
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    /// Every frame of `sim` as text, starting with how it is now, each followed by its status
+    /// if it has one. Frames are cropped around the focus like when headless.
+    pub fn frames<'a, S: Simulation>(&self, sim: &'a mut S) -> Frames<'a, S> {
+        Frames {
+            sim,
+            size: self.size.unwrap_or(HEADLESS_SIZE),
+            started: false,
+        }
+    }
+
+    /// Play `sim` until it finishes (when headless) or the user quits
+    pub fn play(&self, sim: &mut impl Simulation) -> io::Result<()> {
+        if self.headless || !io::stdout().is_terminal() {
+            let mut out = io::stdout().lock();
+            for (i, frame) in self.frames(sim).enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "{}", frame)?;
+            }
+            return Ok(());
+        }
+
+        let mut out = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(out, EnterAlternateScreen, cursor::Hide)?;
+        let result = self.frame_loop(sim, &mut out);
+        // Put the terminal back even if drawing failed
+        execute!(out, cursor::Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+        result
+    }
+
+    /// Show a scene that doesn't change until the user quits
+    pub fn show(&self, scene: &impl Render) -> io::Result<()> {
+        self.play(&mut Still(scene))
+    }
+
+    fn frame_loop(&self, sim: &mut impl Simulation, out: &mut impl Write) -> io::Result<()> {
+        let delay = Duration::from_secs_f64(1.0 / self.fps);
+        let mut paused = false;
+        let mut finished = false;
+        loop {
+            let (columns, rows) = terminal::size()?;
+            // Leave the last row for the status line
+            let (width, height) = self
+                .size
+                .unwrap_or((columns as usize, rows.saturating_sub(1) as usize));
+            let view = sim.bounds().crop(sim.focus(), width, height);
+
+            queue!(out, cursor::MoveTo(0, 0))?;
+            for line in frame(sim, view).lines() {
+                queue!(out, Print(line), Clear(ClearType::UntilNewLine))?;
+                queue!(out, cursor::MoveToNextLine(1))?;
+            }
+            let state = if finished {
+                "finished"
+            } else if paused {
+                "paused"
+            } else {
+                "playing"
+            };
+            queue!(
+                out,
+                Print(format!(
+                    "{} [{}] space: pause, right: step, q: quit",
+                    sim.status(),
+                    state
+                )),
+                Clear(ClearType::FromCursorDown)
+            )?;
+            out.flush()?;
+
+            let step = match next_key(delay)? {
+                Some(Key::Quit) => return Ok(()),
+                Some(Key::Pause) => {
+                    paused = !paused;
+                    false
+                }
+                Some(Key::Step) => {
+                    paused = true;
+                    true
+                }
+                None => !paused,
+            };
+            if step && !finished {
+                finished = !sim.step();
+            }
+        }
+    }
+}
+
+/// The frames of a simulation, from `Player::frames`
+pub struct Frames<'a, S> {
+    sim: &'a mut S,
+    size: (usize, usize),
+    started: bool,
+}
+
+impl<S: Simulation> Iterator for Frames<'_, S> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.started && !self.sim.step() {
+            return None;
+        }
+        self.started = true;
+
+        let (width, height) = self.size;
+        let view = self.sim.bounds().crop(self.sim.focus(), width, height);
+        let mut frame = frame(self.sim, view);
+        let status = self.sim.status();
+        if !status.is_empty() {
+            frame.push('\n');
+            frame.push_str(&status);
+        }
+        Some(frame)
+    }
+}
+
+/// A scene played as a simulation that has already finished
+struct Still<'a, R>(&'a R);
+
+impl<R: Render> Render for Still<'_, R> {
+    fn bounds(&self) -> Bounds {
+        self.0.bounds()
+    }
+
+    fn cell(&self, pos: Point<i64>) -> char {
+        self.0.cell(pos)
+    }
+
+    fn focus(&self) -> Option<Point<i64>> {
+        self.0.focus()
+    }
+
+    fn status(&self) -> String {
+        self.0.status()
+    }
+}
+
+impl<R: Render> Simulation for Still<'_, R> {
+    fn step(&mut self) -> bool {
+        false
+    }
+}
+
+enum Key {
+    Pause,
+    Step,
+    Quit,
+}
+
+/// Wait up to `timeout` for a key that means something to the player
+fn next_key(timeout: Duration) -> io::Result<Option<Key>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+            return Ok(None);
+        }
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        return Ok(Some(match key.code {
+            KeyCode::Char(' ') => Key::Pause,
+            KeyCode::Right | KeyCode::Char('.') => Key::Step,
+            KeyCode::Char('q') | KeyCode::Esc => Key::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Key::Quit,
+            _ => continue,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        animate::{Bounds, Player, Render, Simulation, HEADLESS_SIZE},
+        Point,
+    };
+
+    /// A dot moving right along a line of five cells
+    struct Dot(i64);
+
+    impl Render for Dot {
+        fn bounds(&self) -> Bounds {
+            Bounds::new(Point::new(0, 0), Point::new(4, 0))
+        }
+
+        fn cell(&self, pos: Point<i64>) -> char {
+            if pos.x == self.0 {
+                'o'
+            } else {
+                '.'
+            }
+        }
+
+        fn focus(&self) -> Option<Point<i64>> {
+            Some(Point::new(self.0, 0))
+        }
+    }
+
+    impl Simulation for Dot {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 < 5
+        }
+    }
+
+    #[test]
+    fn crop() {
+        let bounds = Bounds::new(Point::new(-10, 0), Point::new(10, 4));
+        assert_eq!(bounds.crop(None, 100, 100), bounds);
+        assert_eq!(
+            bounds.crop(Some(Point::new(0, 0)), 5, 3),
+            Bounds::new(Point::new(-2, 0), Point::new(2, 2))
+        );
+        // Stops at the edges rather than showing what's past them
+        assert_eq!(
+            bounds.crop(Some(Point::new(10, 4)), 5, 3),
+            Bounds::new(Point::new(6, 2), Point::new(10, 4))
+        );
+        assert_eq!(
+            Bounds::around([Point::new(3, -1), Point::new(-2, 5)]),
+            Some(Bounds::new(Point::new(-2, -1), Point::new(3, 5)))
+        );
+    }
+
+    #[test]
+    fn headless() {
+        let frames: Vec<_> = Player::new().frames(&mut Dot(0)).collect();
+        assert_eq!(frames, ["o....", ".o...", "..o..", "...o.", "....o"]);
+
+        let frames: Vec<_> = Player::new().size(3, 1).frames(&mut Dot(0)).collect();
+        assert_eq!(frames, ["o..", ".o.", ".o.", ".o.", "..o"]);
+
+        // Huge scenes are cropped even without a size
+        let frames: Vec<_> = Player::new().frames(&mut Huge).collect();
+        assert_eq!(frames[0].lines().count(), HEADLESS_SIZE.1);
+    }
+
+    /// A still scene far too big to print whole
+    struct Huge;
+
+    impl Render for Huge {
+        fn bounds(&self) -> Bounds {
+            Bounds::new(Point::new(-1 << 30, -1 << 30), Point::new(1 << 30, 1 << 30))
+        }
+
+        fn cell(&self, _: Point<i64>) -> char {
+            '.'
+        }
+    }
+
+    impl Simulation for Huge {
+        fn step(&mut self) -> bool {
+            false
+        }
+    }
+}
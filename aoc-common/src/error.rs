@@ -0,0 +1,138 @@
+use std::{fmt, io};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read
+    Io(io::Error),
+    /// The input was read but is not in the expected shape
+    Parse(ParseError),
+    /// This part of the puzzle hasn't been solved yet
+    Unsolved,
+}
+
+impl Error {
+    /// A parse error that isn't about any one place in the input, like the input being empty
+    pub fn parse(message: impl Into<String>) -> Self {
+        Error::Parse(ParseError {
+            message: message.into(),
+            location: None,
+        })
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "could not read input: {}", err),
+            Error::Parse(err) => write!(f, "invalid input: {}", err),
+            Error::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse(_) | Error::Unsolved => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+/// Where in the input a parse error is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The `Line::number` of the offending text
+    pub line: usize,
+    /// Column the offending text starts at, in characters and starting from 1
+    pub column: usize,
+    pub text: String,
+    /// The whole line the offending text is on
+    pub source: String,
+}
+
+impl ParseError {
+    /// Render the error rustc-style, underlining the offending text in `name` (usually the
+    /// input's path)
+    pub fn diagnostic(&self, name: &str) -> String {
+        let location = match &self.location {
+            Some(location) => location,
+            None => return format!("error: {}\n --> {}", self.message, name),
+        };
+        let gutter = " ".repeat(location.line.to_string().len());
+        let underline = "^".repeat(location.text.chars().count().max(1));
+        format!(
+            "error: {}\n{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.message,
+            name,
+            location.line,
+            location.column,
+            location.line,
+            location.source,
+            " ".repeat(location.column - 1),
+            underline,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "{} (line {}, column {})",
+                self.message, location.line, location.column
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Location, ParseError};
+
+    #[test]
+    fn diagnostic() {
+        let err = ParseError {
+            message: "\"Q\" is not a valid Action".to_string(),
+            location: Some(Location {
+                line: 12,
+                column: 3,
+                text: "Q".to_string(),
+                source: "A Q".to_string(),
+            }),
+        };
+        assert_eq!(
+            err.diagnostic("input.txt"),
+            [
+                "error: \"Q\" is not a valid Action",
+                "  --> input.txt:12:3",
+                "   |",
+                "12 | A Q",
+                "   |   ^",
+            ]
+            .join("\n")
+        );
+    }
+}
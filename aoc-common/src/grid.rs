@@ -0,0 +1,243 @@
+use std::{
+    fmt,
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+use crate::{input, Error, Line, Point, Result};
+
+/// A dense rectangular grid of cells, addressed by points with `y` growing downwards. The
+/// top-left cell doesn't have to be at `(0, 0)`, so a grid can cover just the part of a
+/// bigger (or negative) coordinate space that a puzzle uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    origin: Point<i64>,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with its top-left cell at `(0, 0)`, every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::with_origin(Point::new(0, 0), width, height, fill)
+    }
+
+    /// A `width` by `height` grid with its top-left cell at `origin`, every cell set to `fill`
+    pub fn with_origin(origin: Point<i64>, width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            origin,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Read a map with a character per cell, turning each into a cell with `cell`. Characters
+    /// it returns `None` for are reported as not being a valid `what`.
+    pub fn parse(
+        input: impl BufRead,
+        what: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let lines = input::read_lines(input)?;
+        let mut cells = Vec::new();
+        let mut width = None;
+        for line in Line::all(&lines) {
+            let row_width = line.text.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(line.error(
+                    line.text,
+                    format!("expected {} cells, found {}", width.unwrap(), row_width),
+                ));
+            }
+            for (i, c) in line.text.char_indices() {
+                let part = &line.text[i..i + c.len_utf8()];
+                cells.push(
+                    cell(c).ok_or_else(|| {
+                        line.error(part, format!("{:?} is not a valid {}", c, what))
+                    })?,
+                );
+            }
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                origin: Point::new(0, 0),
+                width,
+                height: lines.len(),
+                cells,
+            }),
+            _ => Err(Error::parse(format!("expected a map of {}s", what))),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Coordinates of the top-left cell
+    pub fn origin(&self) -> Point<i64> {
+        self.origin
+    }
+
+    fn index(&self, pos: Point<i64>) -> Option<usize> {
+        let Point { x: dx, y: dy } = pos - self.origin;
+        if dx < 0 || dy < 0 || dx >= self.width as i64 || dy >= self.height as i64 {
+            None
+        } else {
+            Some(dy as usize * self.width + dx as usize)
+        }
+    }
+
+    pub fn contains(&self, pos: Point<i64>) -> bool {
+        self.index(pos).is_some()
+    }
+
+    /// The cell at `pos`, or `None` if it's outside the grid
+    pub fn get(&self, pos: Point<i64>) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Point<i64>) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Coordinates of every cell, a row at a time
+    pub fn positions(&self) -> impl Iterator<Item = Point<i64>> {
+        let Point { x: x0, y: y0 } = self.origin;
+        let (width, height) = (self.width as i64, self.height as i64);
+        (y0..y0 + height).flat_map(move |y| (x0..x0 + width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its coordinates, a row at a time
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i64>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells of row `y`, from left to right
+    pub fn row(&self, y: i64) -> &[T] {
+        let start = self
+            .index(Point::new(self.origin.x, y))
+            .expect("row is outside the grid");
+        &self.cells[start..start + self.width]
+    }
+
+    /// The cells of column `x`, from top to bottom
+    pub fn col(&self, x: i64) -> impl Iterator<Item = &T> {
+        let start = self
+            .index(Point::new(x, self.origin.y))
+            .expect("column is outside the grid");
+        self.cells[start..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Coordinates of the cells above, right of, below and left of `pos` that are in the grid
+    pub fn neighbors4(&self, pos: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        pos.neighbors4().filter(|&pos| self.contains(pos))
+    }
+
+    /// Coordinates of the cells touching `pos`, including diagonally, that are in the grid
+    pub fn neighbors8(&self, pos: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        pos.neighbors8().filter(|&pos| self.contains(pos))
+    }
+}
+
+impl<T> Index<Point<i64>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point<i64>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point<i64>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point<i64>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+/// Draws each cell with its own `Display`, a line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid::Grid, Error, Point};
+
+    fn example() -> Grid<u32> {
+        Grid::parse("123\n456".as_bytes(), "digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let err = Grid::parse("123\n4x6".as_bytes(), "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: 'x' is not a valid digit (line 2, column 2)"
+        );
+        let err = Grid::parse("123\n45".as_bytes(), "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: expected 3 cells, found 2 (line 2, column 1)"
+        );
+        let err = Grid::parse("".as_bytes(), "digit", |c| c.to_digit(10)).unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.col(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.iter().map(|(pos, _)| pos).last(),
+            Some(Point::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = example();
+        let mut four: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        four.sort();
+        assert_eq!(four, vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn offset_origin() {
+        let mut grid = Grid::with_origin(Point::new(-2, -1), 3, 2, '.');
+        grid[Point::new(-2, -1)] = '#';
+        grid[Point::new(0, 0)] = 'o';
+        assert_eq!(grid.get(Point::new(1, 0)), None);
+        assert_eq!(grid.to_string(), "#..\n..o");
+        assert_eq!(grid.col(0).collect::<String>(), ".o");
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    animate::{Bounds, Render},
+    Grid, Point,
+};
+
+/// A color as red, green and blue
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// The color in between `low` and `high` a fraction `t` of the way along, for heatmaps
+pub fn gradient(low: Rgb, high: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |i: usize| (low[i] as f64 + (high[i] as f64 - low[i] as f64) * t).round() as u8;
+    [mix(0), mix(1), mix(2)]
+}
+
+/// The colors to draw each character of a scene in
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: Vec<(char, Rgb)>,
+    default: Rgb,
+}
+
+impl Palette {
+    /// A palette drawing every character in `default`
+    pub fn new(default: Rgb) -> Self {
+        Palette {
+            colors: Vec::new(),
+            default,
+        }
+    }
+
+    /// This palette, but drawing `c` in `color`
+    pub fn with(mut self, c: char, color: Rgb) -> Self {
+        self.colors.push((c, color));
+        self
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        self.colors
+            .iter()
+            .find(|&&(x, _)| x == c)
+            .map_or(self.default, |&(_, color)| color)
+    }
+}
+
+/// A picture with a color per pixel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// A `width` by `height` picture filled with `fill`
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// Draw each cell of `grid` as a `scale` pixel square, colored by `palette` from the cell's
+    /// position and value
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        palette: impl Fn(Point<i64>, &T) -> Rgb,
+    ) -> Self {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, BLACK);
+        for (pos, cell) in grid.iter() {
+            let Point { x, y } = pos - grid.origin();
+            image.fill_square(x as usize, y as usize, scale, palette(pos, cell));
+        }
+        image
+    }
+
+    /// Draw the part of `scene` inside `view`, each cell as a `scale` pixel square
+    pub fn from_scene(
+        scene: &(impl Render + ?Sized),
+        view: Bounds,
+        scale: usize,
+        palette: &Palette,
+    ) -> Self {
+        let mut image = Image::new(view.width() * scale, view.height() * scale, BLACK);
+        for y in view.min.y..=view.max.y {
+            for x in view.min.x..=view.max.x {
+                let color = palette.color(scene.cell(Point::new(x, y)));
+                let (dx, dy) = ((x - view.min.x) as usize, (y - view.min.y) as usize);
+                image.fill_square(dx, dy, scale, color);
+            }
+        }
+        image
+    }
+
+    fn fill_square(&mut self, x: usize, y: usize, scale: usize, color: Rgb) {
+        for py in y * scale..(y + 1) * scale {
+            let row = py * self.width;
+            self.pixels[row + x * scale..row + (x + 1) * scale].fill(color);
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of the pixel `x` across and `y` down, or `None` if it's outside the image
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Every pixel's red, green and blue, a row at a time
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.concat()
+    }
+
+    /// Write the image as a binary PPM, the simplest format most viewers understand
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        Ok(writer.finish()?)
+    }
+
+    /// Write the image to `path`, as a PPM if it ends in `.ppm` and a PNG otherwise
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut out = BufWriter::new(File::create(path)?);
+        if path.extension().is_some_and(|x| x == "ppm") {
+            self.write_ppm(&mut out)?;
+        } else {
+            self.write_png(&mut out)?;
+        }
+        out.flush()
+    }
+}
+
+/// Records images as the frames of an animated GIF, which loops forever
+pub struct Recorder<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    /// How long each frame is shown for, in hundredths of a second
+    delay: u16,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Record a GIF to the file at `path`
+    pub fn create(
+        path: impl AsRef<Path>,
+        width: usize,
+        height: usize,
+        fps: f64,
+    ) -> io::Result<Self> {
+        Recorder::new(BufWriter::new(File::create(path)?), width, height, fps)
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Record a GIF of `width` by `height` frames to `out`, showing `fps` frames a second.
+    /// GIF delays are in hundredths of a second, so `fps` is rounded to fit.
+    pub fn new(out: W, width: usize, height: usize, fps: f64) -> io::Result<Self> {
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "too big for a GIF");
+        let width = u16::try_from(width).map_err(|_| too_big())?;
+        let height = u16::try_from(height).map_err(|_| too_big())?;
+        let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(Recorder {
+            encoder,
+            width,
+            height,
+            delay: (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16,
+        })
+    }
+
+    /// Add `image` as the next frame. It must be the size the recorder was created with.
+    pub fn record(&mut self, image: &Image) -> io::Result<()> {
+        if (image.width, image.height) != (self.width as usize, self.height as usize) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a {}x{} frame doesn't fit a {}x{} GIF",
+                    image.width, image.height, self.width, self.height
+                ),
+            ));
+        }
+
+        // Puzzle pictures only use a few colors, so they can almost always be stored exactly
+        let mut palette: Vec<Rgb> = Vec::new();
+        let mut indices = Vec::with_capacity(image.pixels.len());
+        for &pixel in &image.pixels {
+            let index = match palette.iter().position(|&x| x == pixel) {
+                Some(index) => index,
+                None => {
+                    palette.push(pixel);
+                    palette.len() - 1
+                }
+            };
+            if index > u8::MAX as usize {
+                break;
+            }
+            indices.push(index as u8);
+        }
+        let mut frame = if palette.len() <= 256 {
+            gif::Frame::from_palette_pixels(
+                self.width,
+                self.height,
+                indices,
+                palette.concat(),
+                None,
+            )
+        } else {
+            gif::Frame::from_rgb_speed(self.width, self.height, &image.bytes(), 10)
+        };
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+
+    /// Finish the GIF, returning what it was written to
+    pub fn finish(self) -> io::Result<W> {
+        let mut out = self.encoder.into_inner().map_err(io::Error::other)?;
+        out.flush()?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        animate::{Bounds, Render},
+        image::{gradient, Image, Palette, Recorder, BLACK, WHITE},
+        Grid, Point,
+    };
+
+    struct Checkers;
+
+    impl Render for Checkers {
+        fn bounds(&self) -> Bounds {
+            Bounds::new(Point::new(0, 0), Point::new(2, 1))
+        }
+
+        fn cell(&self, pos: Point<i64>) -> char {
+            if (pos.x + pos.y) % 2 == 0 {
+                '#'
+            } else {
+                '.'
+            }
+        }
+    }
+
+    #[test]
+    fn draw() {
+        let palette = Palette::new(WHITE).with('#', BLACK);
+        let image = Image::from_scene(&Checkers, Checkers.bounds(), 2, &palette);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(1, 1), Some(BLACK));
+        assert_eq!(image.get(2, 1), Some(WHITE));
+        assert_eq!(image.get(5, 3), Some(WHITE));
+        assert_eq!(image.get(6, 0), None);
+
+        let grid = Grid::parse("09".as_bytes(), "digit", |c| c.to_digit(10)).unwrap();
+        let image = Image::from_grid(&grid, 1, |_, &x| gradient(BLACK, WHITE, x as f64 / 9.0));
+        assert_eq!(image.get(1, 0), Some(WHITE));
+        assert_eq!(gradient(BLACK, [200, 100, 0], 0.5), [100, 50, 0]);
+    }
+
+    #[test]
+    fn encode() {
+        let image = Image::new(3, 2, [255, 0, 0]);
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 3 * 2 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let mut recorder = Recorder::new(Vec::new(), 3, 2, 10.0).unwrap();
+        recorder.record(&image).unwrap();
+        recorder.record(&Image::new(3, 2, WHITE)).unwrap();
+        assert!(recorder.record(&Image::new(2, 2, WHITE)).is_err());
+        let gif = recorder.finish().unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+}
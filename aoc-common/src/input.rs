@@ -0,0 +1,123 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use crate::{Line, Result};
+
+/// Open the puzzle input at `path`, or standard input if `path` is `-`
+pub fn open(path: impl AsRef<Path>) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// Read every remaining line of `input`, without the trailing newlines
+pub fn read_lines(input: impl BufRead) -> Result<Vec<String>> {
+    Ok(input.lines().collect::<io::Result<Vec<String>>>()?)
+}
+
+/// Split `input` into groups of lines separated by blank lines, reading a group at a time.
+/// Runs of blank lines count as one separator, and blank lines at either end are ignored.
+pub fn groups<B: BufRead>(input: B) -> Groups<B> {
+    Groups {
+        lines: input.lines(),
+        number: 0,
+    }
+}
+
+/// The groups of lines from `groups`
+pub struct Groups<B> {
+    lines: io::Lines<B>,
+    /// Number of the last line read
+    number: usize,
+}
+
+impl<B: BufRead> Iterator for Groups<B> {
+    type Item = Result<Group>;
+
+    fn next(&mut self) -> Option<Result<Group>> {
+        let mut group = Group {
+            first: 0,
+            lines: Vec::new(),
+        };
+        for line in self.lines.by_ref() {
+            self.number += 1;
+            let text = match line {
+                Ok(text) => text,
+                Err(err) => return Some(Err(err.into())),
+            };
+            if text.trim().is_empty() {
+                if group.lines.is_empty() {
+                    continue;
+                }
+                return Some(Ok(group));
+            }
+            if group.lines.is_empty() {
+                group.first = self.number;
+            }
+            group.lines.push(text);
+        }
+        // The last group doesn't have to be followed by a blank line
+        (!group.lines.is_empty()).then_some(Ok(group))
+    }
+}
+
+/// A run of lines without any blank ones in between
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// Line number of the first line
+    first: usize,
+    lines: Vec<String>,
+}
+
+impl Group {
+    /// The group's lines, numbered by where they are in the whole input
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Line::new(self.first + i, text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{groups, read_lines};
+
+    #[test]
+    fn lines_from_a_string() {
+        let lines = read_lines("R 4\r\nU 4\n\nL 3".as_bytes()).unwrap();
+        assert_eq!(lines, vec!["R 4", "U 4", "", "L 3"]);
+    }
+
+    fn numbered_groups(input: &str) -> Vec<Vec<(usize, String)>> {
+        groups(input.as_bytes())
+            .map(|group| {
+                let group = group.unwrap();
+                group
+                    .lines()
+                    .map(|x| (x.number, x.text.to_string()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn blank_line_groups() {
+        let expected = vec![
+            vec![(1, "1".to_string()), (2, "2".to_string())],
+            vec![(4, "3".to_string())],
+        ];
+        assert_eq!(numbered_groups("1\n2\n\n3\n"), expected);
+        assert_eq!(numbered_groups("1\n2\n\n3"), expected);
+        assert_eq!(numbered_groups("1\r\n2\r\n\r\n3\r\n"), expected);
+        assert_eq!(numbered_groups("1\n2\n\n3\n\n\n"), expected);
+        assert_eq!(numbered_groups("\n\n1\n2\n\n3")[1][0], (6, "3".to_string()));
+        assert!(numbered_groups("").is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use std::ops::RangeInclusive;
+
+use crate::point::Coordinate;
+
+/// A set of integers stored as sorted, disjoint inclusive ranges, so huge spans cost no more
+/// than small ones. Ranges that overlap or touch are merged as they're inserted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Add every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Ranges before `first` end too early to touch the new one, and ranges from `last` on
+        // start too late. Only the lower of two values is stepped up, so this can't overflow.
+        let first = self
+            .ranges
+            .partition_point(|&(_, b)| b < start && b + T::ONE < start);
+        let last = self
+            .ranges
+            .partition_point(|&(a, _)| a <= end || end + T::ONE == a);
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Take every value in `range` out of the set
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let mut kept = Vec::with_capacity(self.ranges.len() + 1);
+        for &(a, b) in &self.ranges {
+            if b < start || a > end {
+                kept.push((a, b));
+                continue;
+            }
+            if a < start {
+                kept.push((a, start - T::ONE));
+            }
+            if b > end {
+                kept.push((end + T::ONE, b));
+            }
+        }
+        self.ranges = kept;
+    }
+
+    /// Values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// Values in this set but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a1, b1) = self.ranges[i];
+            let (a2, b2) = other.ranges[j];
+            let (start, end) = (a1.max(a2), b1.min(b2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever range ends first can't overlap anything else in the other set
+            if b1 < b2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.contains_range(value..=value)
+    }
+
+    /// Whether every value in `range` is in the set
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        let i = self.ranges.partition_point(|&(_, b)| b < start);
+        self.ranges
+            .get(i)
+            .is_some_and(|&(a, b)| a <= start && end <= b)
+    }
+
+    /// Whether any value in `range` is in the set
+    pub fn overlaps(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        let i = self.ranges.partition_point(|&(_, b)| b < start);
+        start <= end && self.ranges.get(i).is_some_and(|&(a, _)| a <= end)
+    }
+
+    /// Number of values in the set. This overflows if there are more than `T` can count, like
+    /// every `i32`, so a set that large needs a wider `T`.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |total, &(a, b)| total + (b - a) + T::ONE)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges making up the set, from lowest to highest
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(a, b)| a..=b)
+    }
+}
+
+impl<T: Coordinate> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coordinate> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::IntervalSet;
+
+    #[test]
+    fn insert_and_merge() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=12);
+        set.insert(1..=3);
+        set.insert(5..=6);
+        set.insert(4..=4);
+        // Empty, so ignored
+        let (start, end) = (8, 7);
+        set.insert(start..=end);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=6, 10..=12]);
+        assert_eq!(set.len(), 9);
+
+        set.insert(0..=20);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..=20]);
+        assert!(IntervalSet::<u8>::new().is_empty());
+
+        // Ranges at either end of the type, too far apart to count the gap between them
+        let mut set: IntervalSet<i8> = [-128..=-127, 126..=127].into_iter().collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![-128..=-127, 126..=127]);
+        set.insert(-126..=0);
+        set.insert(1..=125);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![-128..=127]);
+    }
+
+    #[test]
+    fn remove_and_difference() {
+        let mut set: IntervalSet<i64> = [-5..=5, 10..=20].into_iter().collect();
+        set.remove(0..=0);
+        set.remove(4..=12);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![-5..=-1, 1..=3, 13..=20]
+        );
+
+        let other = IntervalSet::from(-10..=1);
+        assert_eq!(
+            set.difference(&other).iter().collect::<Vec<_>>(),
+            vec![2..=3, 13..=20]
+        );
+        assert_eq!(
+            set.union(&other).iter().collect::<Vec<_>>(),
+            vec![-10..=3, 13..=20]
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let a: IntervalSet<i32> = [1..=5, 8..=12, 20..=30].into_iter().collect();
+        let b: IntervalSet<i32> = [4..=9, 11..=25].into_iter().collect();
+        assert_eq!(
+            a.intersection(&b).iter().collect::<Vec<_>>(),
+            vec![4..=5, 8..=9, 11..=12, 20..=25]
+        );
+        assert!(a.intersection(&IntervalSet::from(6..=7)).is_empty());
+    }
+
+    #[test]
+    fn membership() {
+        let set: IntervalSet<i32> = [1..=5, 8..=12].into_iter().collect();
+        assert!(set.contains(1) && set.contains(12));
+        assert!(!set.contains(6) && !set.contains(0));
+        assert!(set.contains_range(2..=5));
+        assert!(!set.contains_range(4..=8));
+        assert!(set.overlaps(4..=8));
+        assert!(!set.overlaps(6..=7));
+        assert!(!set.overlaps(13..=100));
+    }
+}
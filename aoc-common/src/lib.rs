@@ -0,0 +1,21 @@
+//! Shared helpers for the Advent of Code 2022 solutions: the `Solution` trait every day
+//! implements, input loading, small parsing utilities, a common error type, data structures
+//! several days share, a player for animating their simulations in the terminal and image export.
+
+pub mod animate;
+pub mod error;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod point;
+pub mod search;
+pub mod solution;
+
+pub use error::{Error, ParseError, Result};
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use parse::Line;
+pub use point::{Compass, Direction, Point};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,116 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    error::{Location, ParseError},
+    Error, Result,
+};
+
+/// One line of puzzle input, so parse errors can point at where in the input they are. Lines
+/// are numbered from 1, like an editor does, and so is every `line` kept from one.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line { number, text }
+    }
+
+    /// Number the lines of a whole input
+    pub fn all(lines: &'a [String]) -> impl Iterator<Item = Line<'a>> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Line::new(i + 1, text))
+    }
+
+    /// An error about `part`, which should be a slice of this line
+    pub fn error(&self, part: &str, message: impl Display) -> Error {
+        Error::Parse(ParseError {
+            message: message.to_string(),
+            location: Some(Location {
+                line: self.number,
+                column: self.text[..self.offset_of(part)].chars().count() + 1,
+                text: part.to_string(),
+                source: self.text.to_string(),
+            }),
+        })
+    }
+
+    /// An error about the whole line
+    pub fn invalid(&self, what: &str) -> Error {
+        self.error(
+            self.text,
+            format!("{:?} is not a valid {}", self.text, what),
+        )
+    }
+
+    /// Split `part` of this line around the first occurrence of `delimiter`
+    pub fn split_once(&self, part: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, format!("expected {:?} in {:?}", delimiter, part)))
+    }
+
+    /// Parse `part` of this line as a number (or anything else implementing `FromStr`)
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.parse()
+            .map_err(|err| self.error(part, format!("{:?} is not a valid number: {}", part, err)))
+    }
+
+    /// Where `part` starts within this line, in bytes. Falls back to searching for it when it
+    /// isn't actually a slice of the line.
+    fn offset_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        if part_start >= start && part_start + part.len() <= start + self.text.len() {
+            part_start - start
+        } else {
+            self.text.find(part).unwrap_or(0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{Location, ParseError},
+        parse::Line,
+        Error,
+    };
+
+    fn location(err: Error) -> Location {
+        match err {
+            Error::Parse(ParseError {
+                location: Some(location),
+                ..
+            }) => location,
+            err => panic!("expected a located parse error, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn split() {
+        let line = Line::new(1, "2-4,6-8");
+        let (left, right) = line.split_once(line.text, ",").unwrap();
+        assert_eq!((left, right), ("2-4", "6-8"));
+
+        let err = location(line.split_once(right, ",").unwrap_err());
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 5, "6-8"));
+    }
+
+    #[test]
+    fn numbers() {
+        let line = Line::new(3, "R -12 x");
+        assert_eq!(line.parse::<i32>(&line.text[2..5]).unwrap(), -12);
+
+        let err = location(line.parse::<usize>(&line.text[2..5]).unwrap_err());
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 3, "-12"));
+        assert_eq!(err.source, "R -12 x");
+    }
+}
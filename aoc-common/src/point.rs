@@ -0,0 +1,286 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Numbers that can be the coordinates of a `Point`
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+/// Coordinates that can be negative, so points can move in every direction
+pub trait Signed: Coordinate + Neg<Output = Self> {}
+
+macro_rules! coordinate {
+    ($($t:ty)*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+coordinate!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn signum<T: Signed>(a: T) -> T {
+    match a.cmp(&T::ZERO) {
+        Ordering::Less => -T::ONE,
+        Ordering::Equal => T::ZERO,
+        Ordering::Greater => T::ONE,
+    }
+}
+
+/// A position or offset in two dimensions. Like `Grid`, `y` grows downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// This point with coordinates of another type, or `None` if they don't fit in it
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Distance moving only horizontally and vertically
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Distance moving diagonally as well, like a king in chess
+    pub fn chebyshev(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Signed> Point<T> {
+    /// The point one step away in `direction`
+    pub fn step(self, direction: impl Into<Compass>) -> Self {
+        self + direction.into().offset()
+    }
+
+    /// The points above, right of, below and left of this one
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |x| self.step(x))
+    }
+
+    /// The points touching this one, including diagonally, clockwise from the one above
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Compass::ALL.into_iter().map(move |x| self.step(x))
+    }
+
+    /// Each coordinate replaced by -1, 0 or 1 depending on its sign: a single step towards
+    /// where this offset points
+    pub fn signum(self) -> Self {
+        Point::new(signum(self.x), signum(self.y))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+/// The four ways to move without going diagonally
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        Compass::from(self).offset()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// The eight ways to move, including diagonally. North is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Clockwise from `North`
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let (x, y) = match self {
+            Compass::North => (zero, -one),
+            Compass::NorthEast => (one, -one),
+            Compass::East => (one, zero),
+            Compass::SouthEast => (one, one),
+            Compass::South => (zero, one),
+            Compass::SouthWest => (-one, one),
+            Compass::West => (-one, zero),
+            Compass::NorthWest => (-one, -one),
+        };
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::{Compass, Direction, Point};
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(a * 2, Point::new(6, -4));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(0u32, 7);
+        let b = Point::new(3, 2);
+        assert_eq!(a.manhattan(&b), 8);
+        assert_eq!(a.chebyshev(&b), 5);
+    }
+
+    #[test]
+    fn directions() {
+        let origin = Point::new(0i64, 0);
+        assert_eq!(origin.step(Direction::Up), Point::new(0, -1));
+        assert_eq!(origin.step(Compass::SouthWest), Point::new(-1, 1));
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(origin.neighbors4().count(), 4);
+        assert!(origin.neighbors8().all(|x| x.chebyshev(&origin) == 1));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            Point::new(3i64, 4).try_cast::<usize>(),
+            Some(Point::new(3, 4))
+        );
+        assert_eq!(Point::new(-1i64, 4).try_cast::<usize>(), None);
+        assert_eq!(Point::new(1u8, 2).try_cast::<i32>(), Some(Point::new(1, 2)));
+    }
+}
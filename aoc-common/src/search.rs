@@ -0,0 +1,234 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    iter,
+    ops::Add,
+};
+
+/// A shortest path found by a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Total cost of the path, which for `bfs` is the number of steps
+    pub cost: C,
+    /// Every node on the path, from the start to the goal
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search from `start` to the nearest node satisfying `is_goal`, where every
+/// step costs 1
+pub fn bfs<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_from(iter::once(start), neighbors, is_goal)
+}
+
+/// Breadth-first search from whichever of `starts` is closest to a goal
+pub fn bfs_from<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !parents.contains_key(&start) {
+            parents.insert(start.clone(), None);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = reconstruct(node, |x| parents.get(x).and_then(Option::as_ref));
+            return Some(Path { cost: steps, nodes });
+        }
+        for next in neighbors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest node satisfying `is_goal`, where
+/// `neighbors` gives each neighbor along with the cost of stepping to it
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_from(iter::once(start), neighbors, |_| C::default(), is_goal)
+}
+
+/// Dijkstra's algorithm from whichever of `starts` is cheapest to reach a goal from
+pub fn dijkstra_from<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_from(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search from `start` to the cheapest node satisfying `is_goal`. `heuristic` estimates
+/// the cost from a node to the nearest goal, and must never overestimate it.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_from(iter::once(start), neighbors, heuristic, is_goal)
+}
+
+/// A* search from whichever of `starts` is cheapest to reach a goal from
+pub fn astar_from<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The cheapest known cost of reaching each node, and the node it was reached from
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    // The heap holds indices into `queued` so that nodes don't have to be `Ord`
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        best.insert(start.clone(), (C::default(), None));
+        heap.push(Reverse((heuristic(&start), C::default(), queued.len())));
+        queued.push(start);
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let node = queued[i].clone();
+        if cost > best[&node].0 {
+            // Already reached more cheaply since this was queued
+            continue;
+        }
+        if is_goal(&node) {
+            let nodes = reconstruct(node, |x| {
+                best.get(x).and_then(|(_, parent)| parent.as_ref())
+            });
+            return Some(Path { cost, nodes });
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&(known, _)| next_cost < known) {
+                best.insert(next.clone(), (next_cost, Some(node.clone())));
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    queued.len(),
+                )));
+                queued.push(next);
+            }
+        }
+    }
+    None
+}
+
+/// Follow the parents back from `goal` to the start, returning the path from start to goal
+fn reconstruct<'a, N: Clone + 'a>(goal: N, parent: impl Fn(&N) -> Option<&'a N>) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(previous) = parent(nodes.last().unwrap()) {
+        nodes.push(previous.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        search::{astar, bfs, bfs_from, dijkstra},
+        Grid, Point,
+    };
+
+    fn maze() -> Grid<char> {
+        let maze = "\
+            S..#....\n\
+            .#.#.##.\n\
+            .#...#..\n\
+            .####.#.\n\
+            ......#E";
+        Grid::parse(maze.as_bytes(), "tile", Some).unwrap()
+    }
+
+    fn open(maze: &Grid<char>, pos: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        maze.neighbors4(pos).filter(|&x| maze[x] != '#')
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = maze();
+        let end = Point::new(7, 4);
+        let path = bfs(Point::new(0, 0), |&x| open(&maze, x), |&x| x == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!(path.nodes.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert!(path.nodes.windows(2).all(|x| x[0].manhattan(&x[1]) == 1));
+
+        let starts = [Point::new(0, 0), Point::new(7, 0)];
+        let path = bfs_from(starts, |&x| open(&maze, x), |&x| x == end).unwrap();
+        assert_eq!((path.cost, path.nodes[0]), (4, Point::new(7, 0)));
+
+        assert_eq!(bfs(Point::new(0, 0), |&x| open(&maze, x), |_| false), None);
+    }
+
+    #[test]
+    fn weighted() {
+        // Going around is cheaper than the direct edge
+        let edges = |&node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        let path = dijkstra('a', edges, |&x| x == 'd').unwrap();
+        assert_eq!((path.cost, path.nodes), (6, vec!['a', 'b', 'c', 'd']));
+
+        let maze = maze();
+        let end = Point::new(7, 4);
+        let path = astar(
+            Point::new(0, 0),
+            |&x| open(&maze, x).map(|x| (x, 1)),
+            |x| x.manhattan(&end),
+            |&x| x == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 15);
+    }
+}
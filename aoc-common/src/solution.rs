@@ -0,0 +1,101 @@
+use std::{fmt, io::BufRead};
+
+use serde::Serialize;
+
+use crate::{Error, Result};
+
+/// A single day's puzzle: how to read its input and how to solve each part.
+pub trait Solution {
+    /// The day of the advent calendar this solves
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts
+    type Input;
+
+    /// Read the puzzle input, which may come from a file, standard input or a string in tests
+    fn parse(input: impl BufRead) -> Result<Self::Input>;
+
+    /// Ask the questions of the puzzle's worked example rather than the real puzzle, for days
+    /// where they differ (like which row day 15 looks at)
+    fn use_example(_input: &mut Self::Input) {}
+
+    fn part_1(_input: &Self::Input) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+
+    fn part_2(_input: &Self::Input) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
+
+/// Serializes as a JSON number, string, or list of rows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// A picture drawn one row at a time, like day 10's CRT
+    Rows(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Rows(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Rows(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn json() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+        assert_eq!(json(Answer::Number(24000)), "24000");
+        assert_eq!(json(Answer::Text("CMZ".to_string())), "\"CMZ\"");
+        assert_eq!(
+            json(Answer::Rows(vec!["#.".to_string(), ".#".to_string()])),
+            "[\"#.\",\".#\"]"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::BufRead;
+
+use aoc_common::{animate::Player, Result, Solution};
+
+use crate::runner::Part;
+
+/// Parses a day's input and plays its simulation of one part
+pub type Animation = fn(&mut dyn BufRead, Part, &Player) -> Result<()>;
+
+/// How to animate `day`, if it has anything to show
+pub fn animation(day: u8) -> Option<Animation> {
+    match day {
+        9 => Some(rope),
+        10 => Some(crt),
+        14 => Some(sand),
+        15 => Some(sensors),
+        _ => None,
+    }
+}
+
+fn rope(input: &mut dyn BufRead, part: Part, player: &Player) -> Result<()> {
+    let actions = day_9::Day9::parse(input)?;
+    let knots = match part {
+        Part::One => 2,
+        Part::Two => 10,
+    };
+    Ok(player.play(&mut day_9::Rope::new(&actions, knots))?)
+}
+
+fn crt(input: &mut dyn BufRead, _part: Part, player: &Player) -> Result<()> {
+    let state = day_10::Day10::parse(input)?;
+    Ok(player.play(&mut day_10::Crt::new(&state))?)
+}
+
+fn sand(input: &mut dyn BufRead, part: Part, player: &Player) -> Result<()> {
+    let cave = day_14::Day14::parse(input)?;
+    Ok(player.play(&mut day_14::Sandfall::new(cave, part == Part::Two))?)
+}
+
+fn sensors(input: &mut dyn BufRead, _part: Part, player: &Player) -> Result<()> {
+    let cave = day_15::Day15::parse(input)?;
+    Ok(player.show(&cave)?)
+}
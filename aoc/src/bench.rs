@@ -0,0 +1,112 @@
+use std::{fs, io, path::Path, time::Duration};
+
+use aoc_common::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{Part, DAYS};
+
+/// Phases faster than this are too noisy to flag as regressions
+const NOISE_MS: f64 = 0.1;
+
+/// How long one phase of a day (parsing, or solving a part) took over every run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: String,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub max_ms: f64,
+}
+
+impl Timing {
+    fn new(day: u8, phase: String, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (ms(samples[middle - 1]) + ms(samples[middle])) / 2.0
+        } else {
+            ms(samples[middle])
+        };
+        Timing {
+            day,
+            phase,
+            min_ms: ms(samples[0]),
+            median_ms: median,
+            max_ms: ms(samples[samples.len() - 1]),
+        }
+    }
+
+    /// How much slower this is than `baseline`, as a fraction of the baseline's median
+    pub fn slowdown(&self, baseline: &Timing) -> f64 {
+        self.median_ms / baseline.median_ms - 1.0
+    }
+
+    /// Whether this is more than `threshold` percent slower than `baseline`
+    pub fn regressed(&self, baseline: &Timing, threshold: f64) -> bool {
+        self.median_ms - baseline.median_ms > NOISE_MS
+            && self.slowdown(baseline) * 100.0 > threshold
+    }
+}
+
+/// Parse `input` as `day` and solve both of its parts `runs` times. Parts that aren't solved
+/// yet are left out.
+pub fn bench(day: u8, input: &[u8], runs: u32) -> Result<Vec<Timing>> {
+    let mut parse = Vec::new();
+    let mut parts = vec![(Part::One, Vec::new()), (Part::Two, Vec::new())];
+    for _ in 0..runs {
+        let report = DAYS[day as usize - 1](&mut &input[..], &[Part::One, Part::Two], false)?;
+        parse.push(report.parse);
+        for (part, (_, samples)) in report.parts.into_iter().zip(parts.iter_mut()) {
+            match part.answer {
+                Ok(_) => samples.push(part.elapsed),
+                Err(Error::Unsolved) => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    let mut timings = vec![Timing::new(day, "parse".to_string(), parse)];
+    for (part, samples) in parts {
+        if !samples.is_empty() {
+            timings.push(Timing::new(day, format!("part {}", part.number()), samples));
+        }
+    }
+    Ok(timings)
+}
+
+pub fn save(path: &Path, timings: &[Timing]) -> Result<()> {
+    let json = serde_json::to_string_pretty(timings).map_err(io::Error::from)?;
+    fs::write(path, json + "\n")?;
+    Ok(())
+}
+
+pub fn load(path: &Path) -> Result<Vec<Timing>> {
+    let json = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json).map_err(io::Error::from)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Timing;
+
+    #[test]
+    fn timing() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let timing = Timing::new(1, "parse".to_string(), samples);
+        assert_eq!(
+            (timing.min_ms, timing.median_ms, timing.max_ms),
+            (1.0, 2.5, 4.0)
+        );
+
+        let slower = Timing::new(1, "parse".to_string(), vec![Duration::from_millis(3)]);
+        assert!(slower.regressed(&timing, 10.0));
+        assert!(!slower.regressed(&timing, 50.0));
+        // A 100% slowdown from 1µs to 2µs is noise
+        let tiny = Timing::new(1, "parse".to_string(), vec![Duration::from_micros(1)]);
+        let twice = Timing::new(1, "parse".to_string(), vec![Duration::from_micros(2)]);
+        assert!(!twice.regressed(&tiny, 10.0));
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufRead},
+    path::PathBuf,
+};
+
+use aoc_common::{
+    animate::Simulation,
+    image::{gradient, Image, Palette, Recorder, Rgb, BLACK, WHITE},
+    Result, Solution,
+};
+
+use crate::runner::Part;
+
+/// Parses a day's input and writes a picture of one part of it
+pub type Export = fn(&mut dyn BufRead, Part, &Exporter) -> Result<()>;
+
+/// How to draw `day`, if it has anything to show
+pub fn export(day: u8) -> Option<Export> {
+    match day {
+        8 => Some(heatmap),
+        9 => Some(rope),
+        12 => Some(route),
+        14 => Some(sand),
+        _ => None,
+    }
+}
+
+const SKY: Rgb = [24, 28, 40];
+const STONE: Rgb = [120, 120, 130];
+const SAND: Rgb = [230, 190, 110];
+const RED: Rgb = [220, 50, 50];
+const GREEN: Rgb = [40, 120, 60];
+
+/// Where to write pictures and how to draw them
+pub struct Exporter {
+    /// The extension picks the format: `.gif` for an animation, `.ppm`, or otherwise PNG
+    pub path: PathBuf,
+    /// Pixels along each side of a cell
+    pub scale: usize,
+    /// Frames per second of an animation
+    pub fps: f64,
+    /// Steps of a simulation per frame of an animation
+    pub every: usize,
+}
+
+impl Exporter {
+    fn is_gif(&self) -> bool {
+        self.path.extension().is_some_and(|x| x == "gif")
+    }
+
+    fn picture(&self, image: &Image) -> io::Result<()> {
+        if !self.is_gif() {
+            return image.save(&self.path);
+        }
+        let mut recorder = Recorder::create(&self.path, image.width(), image.height(), self.fps)?;
+        recorder.record(image)?;
+        recorder.finish()?;
+        Ok(())
+    }
+
+    /// Draw the simulations `new` makes, as an animation of every `every`th step or otherwise
+    /// a picture of how it ends
+    fn simulation<S: Simulation>(
+        &self,
+        mut new: impl FnMut() -> S,
+        palette: &Palette,
+    ) -> io::Result<()> {
+        // Every frame of a GIF is the same size, so first find everywhere the simulation goes
+        let mut sim = new();
+        let mut view = sim.bounds();
+        while sim.step() {
+            view = view.union(&sim.bounds());
+        }
+        if !self.is_gif() {
+            return Image::from_scene(&sim, view, self.scale, palette).save(&self.path);
+        }
+
+        let (width, height) = (view.width() * self.scale, view.height() * self.scale);
+        let mut recorder = Recorder::create(&self.path, width, height, self.fps)?;
+        let mut sim = new();
+        let mut steps = 0;
+        loop {
+            if steps % self.every == 0 {
+                recorder.record(&Image::from_scene(&sim, view, self.scale, palette))?;
+            }
+            if !sim.step() {
+                break;
+            }
+            steps += 1;
+        }
+        // Always finish on how it ends
+        if steps % self.every != 0 {
+            recorder.record(&Image::from_scene(&sim, view, self.scale, palette))?;
+        }
+        recorder.finish()?;
+        Ok(())
+    }
+}
+
+/// Every tree's scenic score, brighter for better views
+fn heatmap(input: &mut dyn BufRead, _part: Part, exporter: &Exporter) -> Result<()> {
+    let forest = day_8::Day8::parse(input)?;
+    let scores = day_8::scenic_scores(&forest);
+    let best = scores.iter().map(|(_, &x)| x).max().unwrap_or(0).max(1);
+    // The best views are orders of magnitude better than most, so shade on a log scale
+    let image = Image::from_grid(&scores, exporter.scale, |_, &x| {
+        gradient(BLACK, SAND, (x as f64).ln_1p() / (best as f64).ln_1p())
+    });
+    Ok(exporter.picture(&image)?)
+}
+
+/// The trail the rope's tail leaves
+fn rope(input: &mut dyn BufRead, part: Part, exporter: &Exporter) -> Result<()> {
+    let actions = day_9::Day9::parse(input)?;
+    let knots = match part {
+        Part::One => 2,
+        Part::Two => 10,
+    };
+    let palette = Palette::new(WHITE)
+        .with('.', SKY)
+        .with('#', SAND)
+        .with('s', GREEN)
+        .with('H', RED);
+    let new = || day_9::Rope::new(&actions, knots);
+    Ok(exporter.simulation(new, &palette)?)
+}
+
+/// The hills, shaded by height, with the shortest route to the best signal through them
+fn route(input: &mut dyn BufRead, part: Part, exporter: &Exporter) -> Result<()> {
+    let map = day_12::Day12::parse(input)?;
+    let path: HashSet<_> = map.shortest_path(part == Part::Two)?.into_iter().collect();
+    let image = Image::from_grid(map.grid(), exporter.scale, |pos, &val| {
+        if path.contains(&pos) {
+            RED
+        } else {
+            gradient(GREEN, WHITE, day_12::height(val) as f64 / 25.0)
+        }
+    });
+    Ok(exporter.picture(&image)?)
+}
+
+/// Sand piling up in the cave
+fn sand(input: &mut dyn BufRead, part: Part, exporter: &Exporter) -> Result<()> {
+    let cave = day_14::Day14::parse(input)?;
+    let palette = Palette::new(SKY)
+        .with('#', STONE)
+        .with('o', SAND)
+        .with('+', RED);
+    let new = || day_14::Sandfall::new(cave.clone(), part == Part::Two);
+    Ok(exporter.simulation(new, &palette)?)
+}
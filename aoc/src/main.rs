@@ -0,0 +1,746 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_common::{animate::Player, input, Answer, Error, Result, Solution};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use crate::{
+    bench::Timing,
+    runner::{Part, Report, DAYS},
+};
+
+mod animation;
+mod bench;
+mod export;
+mod runner;
+
+/// Run the Advent of Code 2022 solutions
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day to run, or every day if not given
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: Option<u8>,
+
+    /// Which part of the puzzle to solve
+    #[arg(long, value_enum, default_value_t = Parts::All)]
+    part: Parts,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// List the N elves carrying the most calories too (day 1 only)
+    #[arg(long, value_name = "N", requires = "day")]
+    top: Option<usize>,
+
+    /// Summarise every elf's inventory too (day 1 only)
+    #[arg(long, requires = "day")]
+    stats: bool,
+
+    /// List the badge each group of K elves shares too (day 3 only)
+    #[arg(long, value_name = "K", requires = "day")]
+    group_size: Option<usize>,
+
+    /// Find the busiest and unassigned sections, and every overlapping assignment across the
+    /// whole list too (day 4 only)
+    #[arg(long, requires = "day")]
+    coverage: bool,
+
+    /// Score the best, worst and random ways to play against the strategy guide too (day 2
+    /// only)
+    #[arg(long, requires = "day")]
+    analyze: bool,
+
+    /// The input is the puzzle's worked example, which asks a smaller question on some days
+    #[arg(long, requires = "day")]
+    example: bool,
+
+    /// Puzzle input to read, or `-` for standard input. Defaults to the day's `input.txt`.
+    #[arg(requires = "day")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time how long each day takes to parse its input and solve each part
+    Bench(BenchArgs),
+    /// Play a day's simulation in the terminal
+    Animate(AnimateArgs),
+    /// Save a picture of a day's puzzle as a PNG, a PPM or an animated GIF
+    Export(ExportArgs),
+}
+
+#[derive(clap::Args)]
+struct BenchArgs {
+    /// Day to time, or every day if not given
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: Option<u8>,
+
+    /// How many times to run each day
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Save the timings to this file as a JSON baseline for later runs to compare against
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compare the timings to a baseline saved by `--save`, failing if anything got slower
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// How many percent slower than the baseline a median can get before it's a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Puzzle input to read, or `-` for standard input. Defaults to the day's `input.txt`.
+    #[arg(requires = "day")]
+    input: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct AnimateArgs {
+    /// Day to animate: 9, 10, 14 or 15
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: u8,
+
+    /// Which part of the puzzle to show the simulation for
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Frames per second
+    #[arg(long, default_value_t = 10.0, value_parser = positive)]
+    fps: f64,
+
+    /// Crop frames to this many columns rather than the terminal's width, or 160 when headless
+    #[arg(long, requires = "height")]
+    width: Option<usize>,
+
+    /// Crop frames to this many rows rather than the terminal's height, or 48 when headless
+    #[arg(long, requires = "width")]
+    height: Option<usize>,
+
+    /// Print every frame one after another instead of playing them, as happens anyway when the
+    /// output isn't a terminal
+    #[arg(long)]
+    headless: bool,
+
+    /// Puzzle input to read, or `-` for standard input. Defaults to the day's `input.txt`.
+    input: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct ExportArgs {
+    /// Day to draw: 8, 9, 12 or 14
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=DAYS.len() as i64))]
+    day: u8,
+
+    /// Which part of the puzzle to draw
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// File to write. Its extension picks the format: `.gif` animates the day's simulation,
+    /// `.ppm` is a PPM and anything else a PNG.
+    #[arg(long, short)]
+    output: PathBuf,
+
+    /// Pixels along each side of a cell
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    scale: u32,
+
+    /// Frames per second of a GIF
+    #[arg(long, default_value_t = 10.0, value_parser = positive)]
+    fps: f64,
+
+    /// How many steps of the simulation each frame of a GIF moves on by
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+
+    /// Puzzle input to read, or `-` for standard input. Defaults to the day's `input.txt`.
+    input: Option<PathBuf>,
+}
+
+fn positive(value: &str) -> std::result::Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(x) if x > 0.0 => Ok(x),
+        _ => Err("must be a number above 0".to_string()),
+    }
+}
+
+#[derive(Clone, ValueEnum)]
+enum Format {
+    /// A few lines per day, for reading
+    Text,
+    /// One JSON record per part on each line, for scripts
+    Json,
+}
+
+/// The top elves as printed by `--top` with `--format json`
+#[derive(Serialize)]
+struct TopRecord<'a> {
+    day: u8,
+    top: &'a [day_1::Elf],
+}
+
+/// The inventory summary as printed by `--stats` with `--format json`
+#[derive(Serialize)]
+struct StatsRecord<'a> {
+    day: u8,
+    #[serde(flatten)]
+    stats: &'a day_1::Stats,
+}
+
+/// The strategy guide analysis as printed by `--analyze` with `--format json`
+#[derive(Serialize)]
+struct AnalysisRecord<'a> {
+    day: u8,
+    #[serde(flatten)]
+    analysis: &'a day_2::Analysis,
+}
+
+/// The badge report as printed by `--group-size` with `--format json`
+#[derive(Serialize)]
+struct BadgesRecord<'a> {
+    day: u8,
+    group_size: usize,
+    badges: &'a [day_3::Badge],
+}
+
+/// The section coverage as printed by `--coverage` with `--format json`
+#[derive(Serialize)]
+struct CoverageRecord<'a> {
+    day: u8,
+    #[serde(flatten)]
+    coverage: &'a day_4::Coverage,
+}
+
+/// An answer as printed by `--format json`
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    elapsed_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Clone, ValueEnum)]
+enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    All,
+}
+
+impl Parts {
+    fn parts(&self) -> Vec<Part> {
+        match self {
+            Parts::One => vec![Part::One],
+            Parts::Two => vec![Part::Two],
+            Parts::All => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let success = match args.command {
+        Some(Command::Bench(bench)) => run_bench(&bench),
+        Some(Command::Animate(animate)) => run_animate(&animate),
+        Some(Command::Export(export)) => run_export(&export),
+        None => run(&args),
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The days to run: just `day` if given, otherwise all of them
+fn days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=DAYS.len() as u8).collect(),
+    }
+}
+
+fn input_path(day: u8, input: &Option<PathBuf>) -> PathBuf {
+    match input {
+        Some(path) => path.clone(),
+        None => PathBuf::from(format!("day_{}/input.txt", day)),
+    }
+}
+
+/// Solve the requested days and parts, printing the answers. Returns false if any failed.
+fn run(args: &Args) -> bool {
+    if (args.top.is_some() || args.stats) && args.day != Some(1) {
+        eprintln!("--top and --stats only apply to day 1");
+        return false;
+    }
+    if args.analyze && args.day != Some(2) {
+        eprintln!("--analyze only applies to day 2");
+        return false;
+    }
+    if args.group_size.is_some() && args.day != Some(3) {
+        eprintln!("--group-size only applies to day 3");
+        return false;
+    }
+    if args.coverage && args.day != Some(4) {
+        eprintln!("--coverage only applies to day 4");
+        return false;
+    }
+    let mut success = true;
+    for day in days(args.day) {
+        let path = input_path(day, &args.input);
+        // Read it all up front, as the reports below read it again and standard input can only
+        // be read once
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                print_error(day, &path, err);
+                success = false;
+                continue;
+            }
+        };
+        match run_day(day, &input, &args.part.parts(), args.example) {
+            Ok(report) => {
+                success &= match args.format {
+                    Format::Text => print_report(&report),
+                    Format::Json => print_records(&report),
+                }
+            }
+            Err(err) => {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+        if let Some(n) = args.top {
+            if let Err(err) = print_top(&input, n, &args.format) {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+        if args.stats {
+            if let Err(err) = print_stats(&input, &args.format) {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+        if let Some(size) = args.group_size {
+            if let Err(err) = print_badges(&input, size, &args.format) {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+        if args.coverage {
+            if let Err(err) = print_coverage(&input, &args.format) {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+        if args.analyze {
+            if let Err(err) = print_analysis(&input, &args.format) {
+                print_error(day, &path, err);
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// Print the badge of each group of `size` elves, and which groups don't have exactly one
+fn print_badges(input: &[u8], size: usize, format: &Format) -> Result<()> {
+    let badges = day_3::badges(&day_3::Day3::parse(input)?, size)?;
+    if let Format::Json = format {
+        let record = BadgesRecord {
+            day: 3,
+            group_size: size,
+            badges: &badges,
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+        return Ok(());
+    }
+
+    let total: u32 = badges.iter().filter_map(|x| x.priority).sum();
+    println!(
+        "  {} groups of {}: badges worth {}",
+        badges.len(),
+        size,
+        total
+    );
+    println!("  {:>11}  {:>5}  {:>8}", "Lines", "Badge", "Priority");
+    for badge in &badges {
+        let lines = format!("{}-{}", badge.from, badge.to);
+        match (badge.badge, badge.priority) {
+            (Some(item), Some(priority)) => {
+                println!("  {:>11}  {:>5}  {:>8}", lines, item, priority)
+            }
+            _ => println!("  {:>11}  {:>5}  {:>8}", lines, "-", "-"),
+        }
+    }
+    let invalid: Vec<_> = badges.iter().filter(|x| x.badge.is_none()).collect();
+    if !invalid.is_empty() {
+        println!("  {} groups don't have exactly one badge:", invalid.len());
+        for badge in invalid {
+            if badge.candidates.is_empty() {
+                println!("    lines {}-{}: no item in common", badge.from, badge.to);
+            } else {
+                println!(
+                    "    lines {}-{}: {} could all be the badge",
+                    badge.from, badge.to, badge.candidates
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print which sections are assigned to the most elves and to none, and which elves' assignments
+/// overlap across the list
+fn print_coverage(input: &[u8], format: &Format) -> Result<()> {
+    let coverage = day_4::coverage(&day_4::Day4::parse(input)?);
+    if let Format::Json = format {
+        let record = CoverageRecord {
+            day: 4,
+            coverage: &coverage,
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+        return Ok(());
+    }
+
+    let list = |ranges: &[day_4::SectionRange]| {
+        let ranges: Vec<_> = ranges.iter().map(|x| x.to_string()).collect();
+        ranges.join(", ")
+    };
+    match coverage.span {
+        Some(span) => println!("  Sections {} are assigned:", span),
+        None => println!("  No sections are assigned"),
+    }
+    if coverage.most > 0 {
+        println!(
+            "    {} elves are assigned to {}",
+            coverage.most,
+            list(&coverage.busiest)
+        );
+    }
+    if !coverage.uncovered.is_empty() {
+        println!("    nobody is assigned to {}", list(&coverage.uncovered));
+    }
+    println!(
+        "  {} pairs of elves on different lines overlap:",
+        coverage.overlaps.len()
+    );
+    for (a, b) in &coverage.overlaps {
+        println!(
+            "    line {} elf {} and line {} elf {}",
+            a.line, a.number, b.line, b.number
+        );
+    }
+    Ok(())
+}
+
+/// Print how the strategy guide compares to the best, worst and random ways of playing, round
+/// by round, as a table or a JSON record
+fn print_analysis(input: &[u8], format: &Format) -> Result<()> {
+    let analysis = day_2::analyze(&day_2::Day2::parse(input)?)?;
+    if let Format::Json = format {
+        let record = AnalysisRecord {
+            day: 2,
+            analysis: &analysis,
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+        return Ok(());
+    }
+
+    println!("  Over {} rounds:", analysis.rounds.len());
+    println!(
+        "    best {}, worst {}, random {:.1} on average",
+        analysis.best, analysis.worst, analysis.expected
+    );
+    println!(
+        "    the guide scores {} read as moves and {} read as outcomes",
+        analysis.moves, analysis.outcomes
+    );
+    println!(
+        "  {:>5}  {:<8}  {:<8}  {:>4}  {:>5}  {:>6}  {:>5}  {:>8}",
+        "Line", "Opponent", "Best", "Best", "Worst", "Random", "Moves", "Outcomes"
+    );
+    for round in &analysis.rounds {
+        println!(
+            "  {:>5}  {:<8}  {:<8}  {:>4}  {:>5}  {:>6.1}  {:>5}  {:>8}",
+            round.line,
+            round.opponent,
+            round.best_move,
+            round.best,
+            round.worst,
+            round.expected,
+            round.moves,
+            round.outcomes
+        );
+    }
+    Ok(())
+}
+
+/// Print a summary of every elf's inventory, as a table or a JSON record
+fn print_stats(input: &[u8], format: &Format) -> Result<()> {
+    let stats = day_1::stats(input, 10)?;
+    if let Format::Json = format {
+        let record = StatsRecord {
+            day: 1,
+            stats: &stats,
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+        return Ok(());
+    }
+
+    println!("  {} elves carrying:", stats.elves);
+    println!(
+        "    mean {:.1}, median {}, min {}, max {} calories",
+        stats.mean, stats.median, stats.min, stats.max
+    );
+    let most = stats.histogram.iter().map(|x| x.elves).max().unwrap_or(1);
+    for bucket in &stats.histogram {
+        println!(
+            "    {:>7} - {:<7} {:>4} {}",
+            bucket.from,
+            bucket.to,
+            bucket.elves,
+            "#".repeat((bucket.elves * 40).div_ceil(most))
+        );
+    }
+    println!("  {:>5}  {:>5}  {:>8}", "Elf", "Items", "Calories");
+    for elf in &stats.inventories {
+        println!("  {:>5}  {:>5}  {:>8}", elf.number, elf.items, elf.calories);
+    }
+    Ok(())
+}
+
+/// Print the `n` elves carrying the most calories
+fn print_top(input: &[u8], n: usize, format: &Format) -> Result<()> {
+    let top = day_1::top(input, n)?;
+    match format {
+        Format::Text => {
+            println!("  Top {} elves: {} calories", n, day_1::total(&top));
+            for elf in &top {
+                println!("    Elf {}: {}", elf.number, elf.calories);
+            }
+        }
+        Format::Json => {
+            let record = TopRecord { day: 1, top: &top };
+            println!("{}", serde_json::to_string(&record).unwrap());
+        }
+    }
+    Ok(())
+}
+
+fn run_day(day: u8, mut input: &[u8], parts: &[Part], example: bool) -> Result<Report> {
+    DAYS[day as usize - 1](&mut input, parts, example)
+}
+
+/// Read all of the puzzle input at `path`, or standard input if `path` is `-`
+fn read_input(path: &Path) -> Result<Vec<u8>> {
+    let mut input = Vec::new();
+    input::open(path)?.read_to_end(&mut input)?;
+    Ok(input)
+}
+
+/// Print a JSON record for each part. Returns false if any part failed.
+fn print_records(report: &Report) -> bool {
+    let mut success = true;
+    for part in &report.parts {
+        let (answer, error) = match &part.answer {
+            Ok(answer) => (Some(answer), None),
+            Err(err) => {
+                success &= matches!(err, Error::Unsolved);
+                (None, Some(err.to_string()))
+            }
+        };
+        let record = Record {
+            day: report.day,
+            part: part.part.number(),
+            answer,
+            elapsed_ms: part.elapsed.as_secs_f64() * 1000.0,
+            error,
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+    success
+}
+
+/// Time the requested days, printing the timings and comparing them to the baseline if there
+/// is one. Returns false if any day failed or got slower.
+fn run_bench(args: &BenchArgs) -> bool {
+    let baseline = match &args.baseline {
+        Some(path) => match bench::load(path) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("Could not load baseline {}: {}", path.display(), err);
+                return false;
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let mut success = true;
+    let mut timings = Vec::new();
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    for day in days(args.day) {
+        let path = input_path(day, &args.input);
+        let day_timings = match bench_day(day, &path, args.runs) {
+            Ok(day_timings) => day_timings,
+            Err(err) => {
+                print_error(day, &path, err);
+                success = false;
+                continue;
+            }
+        };
+        for timing in day_timings {
+            let previous = baseline
+                .iter()
+                .find(|x| x.day == timing.day && x.phase == timing.phase);
+            success &= print_timing(&timing, previous, args.threshold);
+            timings.push(timing);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(err) = bench::save(path, &timings) {
+            eprintln!("Could not save baseline {}: {}", path.display(), err);
+            success = false;
+        }
+    }
+    success
+}
+
+fn bench_day(day: u8, path: &Path, runs: u32) -> Result<Vec<Timing>> {
+    bench::bench(day, &read_input(path)?, runs)
+}
+
+/// Print a row of the timing table, comparing it to the baseline if there is one. Returns
+/// false if it's a regression.
+fn print_timing(timing: &Timing, baseline: Option<&Timing>, threshold: f64) -> bool {
+    let ms = |ms: f64| format!("{:.3}ms", ms);
+    print!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        timing.day,
+        timing.phase,
+        ms(timing.min_ms),
+        ms(timing.median_ms),
+        ms(timing.max_ms)
+    );
+    let baseline = match baseline {
+        Some(baseline) => baseline,
+        None => {
+            println!();
+            return true;
+        }
+    };
+    let regressed = timing.regressed(baseline, threshold);
+    println!(
+        "  {:+.1}% vs {}{}",
+        timing.slowdown(baseline) * 100.0,
+        ms(baseline.median_ms),
+        if regressed { "  REGRESSION" } else { "" }
+    );
+    !regressed
+}
+
+/// Play the requested day's simulation. Returns false if it has none or it failed.
+fn run_animate(args: &AnimateArgs) -> bool {
+    let Some(animation) = animation::animation(args.day) else {
+        eprintln!("Day {} has nothing to animate", args.day);
+        return false;
+    };
+    let mut player = Player::new().fps(args.fps).headless(args.headless);
+    if let (Some(width), Some(height)) = (args.width, args.height) {
+        player = player.size(width, height);
+    }
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+
+    let path = input_path(args.day, &args.input);
+    let result = input::open(&path).and_then(|mut input| animation(&mut input, part, &player));
+    match result {
+        Ok(()) => true,
+        Err(err) => {
+            print_error(args.day, &path, err);
+            false
+        }
+    }
+}
+
+/// Draw the requested day. Returns false if it has nothing to draw or it failed.
+fn run_export(args: &ExportArgs) -> bool {
+    let Some(export) = export::export(args.day) else {
+        eprintln!("Day {} has nothing to draw", args.day);
+        return false;
+    };
+    let exporter = export::Exporter {
+        path: args.output.clone(),
+        scale: args.scale as usize,
+        fps: args.fps,
+        every: args.every as usize,
+    };
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+
+    let path = input_path(args.day, &args.input);
+    let result = input::open(&path).and_then(|mut input| export(&mut input, part, &exporter));
+    match result {
+        Ok(()) => true,
+        Err(err) => {
+            print_error(args.day, &path, err);
+            false
+        }
+    }
+}
+
+/// Print why a day failed, pointing at the offending input if it didn't parse
+fn print_error(day: u8, path: &Path, err: Error) {
+    match err {
+        Error::Parse(err) => {
+            let name = match path.to_str() {
+                Some("-") => "<stdin>".to_string(),
+                _ => path.display().to_string(),
+            };
+            eprintln!("Day {}: {}", day, err.diagnostic(&name));
+        }
+        err => eprintln!("Day {}: {}", day, err),
+    }
+}
+
+/// Print each answer with how long it took. Returns false if any part failed.
+fn print_report(report: &Report) -> bool {
+    let mut success = true;
+    println!("Day {} (parsed in {:.2?})", report.day, report.parse);
+    for part in &report.parts {
+        let label = format!("Part {}", part.part.number());
+        match &part.answer {
+            Ok(Answer::Rows(rows)) => {
+                println!("  {} ({:.2?}):", label, part.elapsed);
+                for row in rows {
+                    println!("    {}", row);
+                }
+            }
+            Ok(answer) => println!("  {} ({:.2?}): {}", label, part.elapsed, answer),
+            Err(Error::Unsolved) => println!("  {}: {}", label, Error::Unsolved),
+            Err(err) => {
+                println!("  {}: {}", label, err);
+                success = false;
+            }
+        }
+    }
+    success
+}
@@ -0,0 +1,182 @@
+use std::{
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Answer, Result, Solution};
+
+/// Parses a day's input and solves the requested parts of it, as the puzzle's worked example
+/// if the flag is set
+pub type Runner = fn(&mut dyn BufRead, &[Part], bool) -> Result<Report>;
+
+pub const DAYS: [Runner; 16] = [
+    run::<day_1::Day1>,
+    run::<day_2::Day2>,
+    run::<day_3::Day3>,
+    run::<day_4::Day4>,
+    run::<day_5::Day5>,
+    run::<day_6::Day6>,
+    run::<day_7::Day7>,
+    run::<day_8::Day8>,
+    run::<day_9::Day9>,
+    run::<day_10::Day10>,
+    run::<day_11::Day11>,
+    run::<day_12::Day12>,
+    run::<day_13::Day13>,
+    run::<day_14::Day14>,
+    run::<day_15::Day15>,
+    run::<day_16::Day16>,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+fn run<S: Solution>(input: &mut dyn BufRead, parts: &[Part], example: bool) -> Result<Report> {
+    let start = Instant::now();
+    let mut parsed = S::parse(input)?;
+    if example {
+        S::use_example(&mut parsed);
+    }
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_1(&parsed),
+                Part::Two => S::part_2(&parsed),
+            };
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        fs::File,
+        io::BufReader,
+        path::{Path, PathBuf},
+    };
+
+    use aoc_common::Error;
+
+    use super::{Part, DAYS};
+
+    /// The answers in `answers.txt` for one of the inputs, keyed by day and part
+    fn expected(input: &str) -> HashMap<(u8, u8), String> {
+        let mut answers: HashMap<(u8, u8), String> = HashMap::new();
+        let mut last = None;
+        for line in include_str!("../answers.txt").lines() {
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+            if let Some(row) = line.strip_prefix("    ") {
+                // Rows of another input's answer have nowhere to go
+                if let Some(answer) = last.and_then(|key| answers.get_mut(&key)) {
+                    if !answer.is_empty() {
+                        answer.push('\n');
+                    }
+                    answer.push_str(row);
+                }
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            let key = (fields[0].parse().unwrap(), fields[2].parse().unwrap());
+            if fields[1] == input {
+                answers.insert(key, fields.get(3).unwrap_or(&"").to_string());
+                last = Some(key);
+            } else {
+                last = None;
+            }
+        }
+        answers
+    }
+
+    /// Solve every day that has `input`, comparing the answers to the recorded ones
+    fn check(input: &str, example: bool) {
+        let expected = expected(input);
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut failures = Vec::new();
+        for (i, run) in DAYS.iter().enumerate() {
+            let day = i as u8 + 1;
+            let path: PathBuf = root.join(format!("day_{}", day)).join(input);
+            if !path.exists() {
+                continue;
+            }
+            let mut reader = BufReader::new(File::open(&path).unwrap());
+            let report = match run(&mut reader, &[Part::One, Part::Two], example) {
+                Ok(report) => report,
+                Err(err) => {
+                    failures.push(format!("day {}: {}", day, err));
+                    continue;
+                }
+            };
+            for part in report.parts {
+                let expected = expected.get(&(day, part.part.number()));
+                let failure = match (&part.answer, expected) {
+                    (Ok(answer), Some(expected)) if answer.to_string() == *expected => None,
+                    (Err(Error::Unsolved), None) => None,
+                    (Ok(answer), _) => Some(answer.to_string()),
+                    (Err(err), _) => Some(err.to_string()),
+                };
+                if let Some(actual) = failure {
+                    failures.push(format!(
+                        "day {} part {}: expected {:?}, got {:?}",
+                        day,
+                        part.part.number(),
+                        expected.map_or(Error::Unsolved.to_string(), |x| x.to_string()),
+                        actual
+                    ));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn examples() {
+        check("test_input.txt", true);
+    }
+
+    #[test]
+    fn puzzle_inputs() {
+        check("input.txt", false);
+    }
+}
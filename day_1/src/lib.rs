@@ -0,0 +1,223 @@
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+use aoc_common::{
+    input::{self, Group},
+    Answer, Error, Result, Solution,
+};
+use serde::Serialize;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    /// The 3 elves carrying the most calories, which is all either part needs
+    type Input = Vec<Elf>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        top(input, 3)
+    }
+
+    /// Total calories carried by the elf carrying the most
+    fn part_1(elves: &Self::Input) -> Result<Answer> {
+        Ok(total(&elves[..elves.len().min(1)]).into())
+    }
+
+    /// Total calories carried by the top 3 elves
+    fn part_2(elves: &Self::Input) -> Result<Answer> {
+        Ok(total(elves).into())
+    }
+}
+
+/// An elf and what it carries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Elf {
+    /// Where the elf is in the list
+    pub number: usize,
+    pub items: usize,
+    pub calories: i64,
+}
+
+impl Elf {
+    fn parse(number: usize, group: &Group) -> Result<Self> {
+        Ok(Elf {
+            number,
+            items: group.lines().count(),
+            calories: calories(group)?,
+        })
+    }
+}
+
+/// The `n` elves in `input` carrying the most calories, most first. Elves carrying the same
+/// amount are kept in the order they're listed. Only those `n` are kept while reading, so the
+/// list can be any length.
+pub fn top(input: impl BufRead, n: usize) -> Result<Vec<Elf>> {
+    // The best so far by calories, then by who comes first, with the worst of them on top so
+    // it's the one dropped
+    let mut best = BinaryHeap::new();
+    for (i, group) in input::groups(input).enumerate() {
+        let elf = Elf::parse(i + 1, &group?)?;
+        best.push(Reverse((elf.calories, Reverse(elf.number), elf.items)));
+        if best.len() > n {
+            best.pop();
+        }
+    }
+    Ok(best
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(number), items))| Elf {
+            number,
+            items,
+            calories,
+        })
+        .collect())
+}
+
+/// Total calories of the items in one elf's group of lines
+fn calories(group: &Group) -> Result<i64> {
+    group.lines().try_fold(0i64, |total, line| {
+        total
+            .checked_add(line.parse(line.text)?)
+            .ok_or_else(|| line.error(line.text, "too many calories to add up"))
+    })
+}
+
+pub fn total(elves: &[Elf]) -> i64 {
+    elves.iter().map(|elf| elf.calories).sum()
+}
+
+/// How many elves carry between `from` and `to` calories, inclusive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: i64,
+    pub to: i64,
+    pub elves: usize,
+}
+
+/// A summary of every elf's inventory
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub min: i64,
+    pub max: i64,
+    /// The calories carried, split into equal ranges from the least to the most
+    pub histogram: Vec<Bucket>,
+    pub inventories: Vec<Elf>,
+}
+
+/// Summarise every elf's inventory in `input`, with a histogram of up to `buckets` ranges
+pub fn stats(input: impl BufRead, buckets: usize) -> Result<Stats> {
+    let mut inventories = Vec::new();
+    for (i, group) in input::groups(input).enumerate() {
+        inventories.push(Elf::parse(i + 1, &group?)?);
+    }
+    if inventories.is_empty() {
+        return Err(Error::parse("expected at least one elf"));
+    }
+
+    let mut totals: Vec<i64> = inventories.iter().map(|x| x.calories).collect();
+    totals.sort();
+    let middle = totals.len() / 2;
+    let median = if totals.len().is_multiple_of(2) {
+        (totals[middle - 1] as f64 + totals[middle] as f64) / 2.0
+    } else {
+        totals[middle] as f64
+    };
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+
+    // The spread of totals can be wider than an i64 holds. The last bucket stops at `max`.
+    let (min_wide, max_wide) = (min as i128, max as i128);
+    let width = (max_wide - min_wide) / buckets.max(1) as i128 + 1;
+    let mut histogram: Vec<Bucket> = (0..)
+        .map(|i| min_wide + i * width)
+        .take_while(|&from| from <= max_wide)
+        .map(|from| Bucket {
+            from: from as i64,
+            to: (from + width - 1).min(max_wide) as i64,
+            elves: 0,
+        })
+        .collect();
+    for &total in &totals {
+        histogram[((total as i128 - min_wide) / width) as usize].elves += 1;
+    }
+
+    Ok(Stats {
+        elves: totals.len(),
+        mean: totals.iter().map(|&x| x as f64).sum::<f64>() / totals.len() as f64,
+        median,
+        min,
+        max,
+        histogram,
+        inventories,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stats, top, Bucket, Elf};
+
+    fn elf(number: usize, items: usize, calories: i64) -> Elf {
+        Elf {
+            number,
+            items,
+            calories,
+        }
+    }
+
+    fn bucket(from: i64, to: i64, elves: usize) -> Bucket {
+        Bucket { from, to, elves }
+    }
+
+    #[test]
+    fn top_elves() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000\n\n4000";
+        let top = |input: &str, n| top(input.as_bytes(), n).unwrap();
+        assert_eq!(top(input, 2), vec![elf(3, 2, 11000), elf(2, 1, 4000)]);
+        assert_eq!(top(input, 3)[2], elf(4, 1, 4000));
+        assert_eq!(top(input, 10).len(), 4);
+        assert!(top(input, 0).is_empty());
+        assert_eq!(top(input, usize::MAX).len(), 4);
+
+        // The last elf counts whether or not the list ends with a newline
+        let expected = vec![elf(3, 1, 4000), elf(1, 2, 3000)];
+        assert_eq!(top("1000\n2000\n\n3\n\n4000\n", 2), expected);
+        assert_eq!(top("1000\n2000\n\n3\n\n4000", 2), expected);
+        assert_eq!(top("1000\r\n2000\r\n\r\n3\r\n\r\n4000\r\n", 2), expected);
+        // A blank line after the last elf doesn't start an empty one
+        assert_eq!(top("1000\n2000\n\n3\n\n4000\n\n", 10).len(), 3);
+    }
+
+    #[test]
+    fn statistics() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let stats = stats(input.as_bytes(), 3).unwrap();
+        assert_eq!(stats.elves, 5);
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        let items: Vec<_> = stats.inventories.iter().map(|x| x.items).collect();
+        assert_eq!(items, vec![3, 1, 2, 3, 1]);
+
+        assert_eq!(
+            stats.histogram,
+            vec![
+                bucket(4000, 10666, 3),
+                bucket(10667, 17333, 1),
+                bucket(17334, 24000, 1)
+            ]
+        );
+        assert!(super::stats("".as_bytes(), 3).is_err());
+
+        // The last bucket stops at the most any elf carries
+        let stats = super::stats("1\n\n5\n\n10".as_bytes(), 3).unwrap();
+        assert_eq!(stats.histogram[2], bucket(9, 10, 1));
+
+        // Totals further apart than an i64 can count, and more than it can add up
+        let input = format!("{}\n\n{}", i64::MIN, i64::MAX);
+        let stats = super::stats(input.as_bytes(), 2).unwrap();
+        assert_eq!(stats.histogram[0], bucket(i64::MIN, -1, 1));
+        assert_eq!(stats.histogram[1], bucket(0, i64::MAX, 1));
+        let input = format!("{}\n1", i64::MAX);
+        assert!(super::stats(input.as_bytes(), 2).is_err());
+    }
+}
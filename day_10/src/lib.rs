@@ -0,0 +1,178 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    animate::{Bounds, Render, Simulation},
+    Answer, Error, Line, Point, Result, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = State;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let mut state = State::new();
+        for (i, line) in input.lines().enumerate() {
+            match Instruction::parse(&Line::new(i + 1, &line?))? {
+                Instruction::Noop => state.run_noop(),
+                Instruction::AddX(x) => state.run_addx(x),
+            }
+        }
+        Ok(state)
+    }
+
+    /// Sampled signal strength
+    fn part_1(state: &Self::Input) -> Result<Answer> {
+        let mut signal_strength = 0;
+        for (i, value) in state.x_history.iter().enumerate() {
+            let cycle = i as i32 + 1;
+            if (cycle - 20) % 40 == 0 {
+                signal_strength += value * cycle;
+            }
+        }
+        Ok(signal_strength.into())
+    }
+
+    /// The image drawn on the CRT
+    fn part_2(state: &Self::Input) -> Result<Answer> {
+        if state.x_history.len() < 240 {
+            return Err(Error::parse(format!(
+                "the program runs for {} cycles, but the CRT takes 240 to draw",
+                state.x_history.len()
+            )));
+        }
+        Ok(state.draw().into())
+    }
+}
+
+pub struct State {
+    x: i32,
+    cycle: i32,
+    x_history: Vec<i32>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            x: 1,
+            cycle: 0,
+            x_history: Vec::new(),
+        }
+    }
+
+    fn run_noop(&mut self) {
+        self.tick();
+    }
+
+    fn run_addx(&mut self, x: i32) {
+        self.tick();
+        self.tick();
+        self.x += x
+    }
+
+    fn tick(&mut self) {
+        self.cycle += 1;
+        self.x_history.push(self.x);
+    }
+
+    /// X register controls the horizontal position of a sprite. Specifically, the sprite is
+    /// 3 pixels wide, and the X register sets the horizontal position of the middle of that
+    /// sprite. (In this system, there is no such thing as "vertical position": if the sprite's
+    /// horizontal position puts its pixels where the CRT is currently drawing, then those pixels
+    /// will be drawn.)
+    fn draw(&self) -> Vec<String> {
+        (0..6)
+            .map(|y| (0..40).map(|x| self.pixel(x, y)).collect())
+            .collect()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> char {
+        let sprite_pos = self.x_history[x + 40 * y];
+        if (sprite_pos - x as i32).abs() <= 1 {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// The CRT drawing its image a pixel per cycle
+pub struct Crt<'a> {
+    state: &'a State,
+    /// Pixels drawn so far
+    drawn: usize,
+}
+
+impl<'a> Crt<'a> {
+    pub fn new(state: &'a State) -> Self {
+        Crt { state, drawn: 0 }
+    }
+}
+
+impl Simulation for Crt<'_> {
+    fn step(&mut self) -> bool {
+        if self.drawn == self.state.x_history.len().min(240) {
+            return false;
+        }
+        self.drawn += 1;
+        true
+    }
+}
+
+impl Render for Crt<'_> {
+    fn bounds(&self) -> Bounds {
+        Bounds::new(Point::new(0, 0), Point::new(39, 5))
+    }
+
+    fn cell(&self, pos: Point<i64>) -> char {
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        if x + 40 * y < self.drawn {
+            self.state.pixel(x, y)
+        } else {
+            ' '
+        }
+    }
+
+    fn status(&self) -> String {
+        match self.state.x_history.get(self.drawn) {
+            Some(x) => format!("cycle {}, X = {}", self.drawn + 1, x),
+            None => "done".to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+impl Instruction {
+    fn parse(line: &Line) -> Result<Self> {
+        match line.text {
+            "noop" => Ok(Instruction::Noop),
+            _ => {
+                if line.text.starts_with("addx") {
+                    let (_, num) = line.split_once(line.text, " ")?;
+                    Ok(Instruction::AddX(line.parse(num)?))
+                } else {
+                    Err(line.invalid("instruction"))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day10, Solution};
+
+    #[test]
+    fn short_program() {
+        let state = Day10::parse("noop\naddx 3\naddx -5".as_bytes()).unwrap();
+        assert!(Day10::part_1(&state).is_ok());
+        // Too short to draw the whole screen
+        assert!(Day10::part_2(&state).is_err());
+    }
+}
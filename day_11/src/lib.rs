@@ -0,0 +1,306 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    io::BufRead,
+};
+
+use aoc_common::{input, Answer, Error, Line, Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Monkeys;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let lines = input::read_lines(input)?;
+        let lines: Vec<Line> = Line::all(&lines).collect();
+        let count = lines.len().div_ceil(6);
+        if count < 2 {
+            return Err(Error::parse("expected at least two monkeys"));
+        }
+        let mut monkeys = HashMap::new();
+        for (id, chunk) in lines.chunks(6).enumerate() {
+            let monkey = Monkey::parse(chunk, id, count)?;
+            monkeys.insert(monkey.id, monkey);
+        }
+        Ok(Monkeys(monkeys))
+    }
+
+    /// Total monkey business after 20 rounds, with relief after every inspection
+    fn part_1(Monkeys(monkeys): &Self::Input) -> Result<Answer> {
+        let monkeys = monkeys
+            .iter()
+            .map(|(&id, monkey)| (id, monkey.with_items(Relieved)))
+            .collect();
+        Ok(monkey_business(monkeys, 20).into())
+    }
+
+    /// Total monkey business after 10,000 rounds without any relief
+    fn part_2(Monkeys(monkeys): &Self::Input) -> Result<Answer> {
+        let factors: Vec<i64> = monkeys
+            .values()
+            .map(|monkey| match monkey.test.expr {
+                Expr::DivisibleBy(n) => n,
+            })
+            .collect();
+        let monkeys = monkeys
+            .iter()
+            .map(|(&id, monkey)| (id, monkey.with_items(|x| WorryLevel::new(x, &factors))))
+            .collect();
+        Ok(monkey_business(monkeys, 10000).into())
+    }
+}
+
+/// Every monkey, by id, holding their starting items
+pub struct Monkeys(HashMap<usize, Monkey<i64>>);
+
+fn monkey_business<W: Worry>(mut monkeys: HashMap<usize, Monkey<W>>, rounds: usize) -> usize {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let mut monkey = monkeys.remove(&i).unwrap();
+            monkey.execute(&mut monkeys);
+            monkeys.insert(i, monkey);
+        }
+    }
+
+    let mut heap = BinaryHeap::new();
+    for monkey in monkeys.values() {
+        heap.push(monkey.inspections)
+    }
+    heap.pop().unwrap() * heap.pop().unwrap()
+}
+
+#[derive(Debug)]
+struct Monkey<W> {
+    id: usize,
+    starting_items: Vec<W>,
+    operation: Op,
+    test: Test,
+    inspections: usize,
+}
+
+impl Monkey<i64> {
+    /// Parse the `id`th of `count` monkeys, which are listed in order
+    fn parse(lines: &[Line], id: usize, count: usize) -> Result<Self> {
+        let [header, items, operation, test @ ..] = lines else {
+            unreachable!("chunks are never empty")
+        };
+        if test.len() != 3 {
+            let last = lines.last().unwrap();
+            return Err(last.error(last.text, "expected 6 lines describing a monkey"));
+        }
+        let (_, number) = header.split_once(header.text, "Monkey ")?;
+        let number = number
+            .strip_suffix(':')
+            .ok_or_else(|| header.error(number, "expected a ':'"))?;
+        if header.parse::<usize>(number)? != id {
+            return Err(header.error(number, format!("expected monkey {} here", id)));
+        }
+        let starting_items = {
+            let (_, right) = items.split_once(items.text, ": ")?;
+            right
+                .split(", ")
+                .map(|x| items.parse(x))
+                .collect::<Result<Vec<i64>>>()?
+        };
+        let operation = Op::parse(operation)?;
+        let test = Test::parse(test, id, count)?;
+
+        Ok(Monkey {
+            id,
+            starting_items,
+            operation,
+            test,
+            inspections: 0,
+        })
+    }
+
+    /// A copy of this monkey, tracking its items' worry levels as `W`
+    fn with_items<W>(&self, f: impl Fn(i64) -> W) -> Monkey<W> {
+        Monkey {
+            id: self.id,
+            starting_items: self.starting_items.iter().map(|&x| f(x)).collect(),
+            operation: self.operation.clone(),
+            test: self.test.clone(),
+            inspections: self.inspections,
+        }
+    }
+}
+
+impl<W: Worry> Monkey<W> {
+    fn execute(&mut self, monkeys: &mut HashMap<usize, Monkey<W>>) {
+        self.starting_items.reverse();
+        while let Some(mut worry_level) = self.starting_items.pop() {
+            self.inspections += 1;
+            worry_level.apply(&self.operation);
+            // Evaluate the test to pick next monkey
+            match self.test.expr {
+                Expr::DivisibleBy(n) => {
+                    let new_monkey = if worry_level.divisible_by(n) {
+                        monkeys.get_mut(&self.test.if_arm).unwrap()
+                    } else {
+                        monkeys.get_mut(&self.test.else_arm).unwrap()
+                    };
+                    new_monkey.starting_items.push(worry_level);
+                }
+            };
+        }
+    }
+}
+
+trait Worry {
+    fn apply(&mut self, op: &Op);
+
+    fn divisible_by(&self, n: i64) -> bool;
+}
+
+/// The worry level is divided by three after each inspection, because the item wasn't damaged
+#[derive(Debug)]
+struct Relieved(i64);
+
+impl Worry for Relieved {
+    fn apply(&mut self, op: &Op) {
+        let worry = match op {
+            Op::Mult(num) => self.0 * num,
+            Op::Add(num) => self.0 + num,
+            Op::Exp => self.0 * self.0,
+        };
+        self.0 = worry / 3;
+    }
+
+    fn divisible_by(&self, n: i64) -> bool {
+        self.0 % n == 0
+    }
+}
+
+/// Without relief the worry levels grow without bound, so only their remainders modulo each
+/// monkey's test divisor are kept
+#[derive(Debug)]
+struct WorryLevel {
+    mods: HashMap<i64, i64>,
+}
+
+impl WorryLevel {
+    fn new(base: i64, factors: &[i64]) -> Self {
+        let mut mods = HashMap::new();
+        for n in factors {
+            mods.insert(*n, base % n);
+        }
+        WorryLevel { mods }
+    }
+}
+
+impl Worry for WorryLevel {
+    fn apply(&mut self, op: &Op) {
+        for (n, entry) in self.mods.iter_mut() {
+            match op {
+                Op::Mult(num) => *entry = (*entry * num) % n,
+                Op::Add(num) => *entry = (*entry + num) % n,
+                Op::Exp => *entry = (*entry * *entry) % n,
+            }
+        }
+    }
+
+    fn divisible_by(&self, n: i64) -> bool {
+        self.mods.get(&n).unwrap() == &0
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Mult(i64),
+    Add(i64),
+    Exp,
+}
+
+impl Op {
+    fn parse(line: &Line) -> Result<Self> {
+        let (_, val) = line.split_once(line.text, "=")?;
+        if let Some((_, right)) = val.split_once(" * ") {
+            if right == "old" {
+                return Ok(Op::Exp);
+            } else {
+                return Ok(Op::Mult(line.parse(right)?));
+            }
+        } else if let Some((_, right)) = val.split_once(" + ") {
+            return Ok(Op::Add(line.parse(right)?));
+        }
+        Err(line.error(val, "not a valid operation expression"))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    expr: Expr,
+    if_arm: usize,
+    else_arm: usize,
+}
+
+impl Test {
+    /// Parse the test of monkey `id`, which can throw to any of the other `count` monkeys
+    fn parse(lines: &[Line], id: usize, count: usize) -> Result<Test> {
+        let expr = Expr::parse(&lines[0])?;
+        let target = |line: &Line| {
+            let (_, target) = line.split_once(line.text, " monkey ")?;
+            match line.parse::<usize>(target)? {
+                n if n == id => Err(line.error(target, "a monkey can't throw to itself")),
+                n if n >= count => Err(line.error(target, format!("there's no monkey {}", n))),
+                n => Ok(n),
+            }
+        };
+        Ok(Test {
+            expr,
+            if_arm: target(&lines[1])?,
+            else_arm: target(&lines[2])?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    DivisibleBy(i64),
+}
+
+impl Expr {
+    fn parse(line: &Line) -> Result<Expr> {
+        if let Some((_, right)) = line.text.split_once(" by ") {
+            match line.parse(right)? {
+                n if n <= 0 => Err(line.error(right, "expected a positive divisor")),
+                n => Ok(Expr::DivisibleBy(n)),
+            }
+        } else {
+            Err(line.invalid("test expression"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day11, Solution};
+
+    const MONKEYS: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn monkeys() {
+        assert!(Day11::parse(MONKEYS.as_bytes()).is_ok());
+        // Too few monkeys for any monkey business
+        assert!(Day11::parse("".as_bytes()).is_err());
+        assert!(Day11::parse(&MONKEYS.as_bytes()[..MONKEYS.find("Monkey 1").unwrap()]).is_err());
+        // Out of order, throwing to itself or to a monkey that isn't there
+        assert!(Day11::parse(MONKEYS.replace("Monkey 1", "Monkey 2").as_bytes()).is_err());
+        assert!(Day11::parse(MONKEYS.replacen("monkey 1", "monkey 0", 1).as_bytes()).is_err());
+        assert!(Day11::parse(MONKEYS.replacen("monkey 1", "monkey 5", 1).as_bytes()).is_err());
+        assert!(Day11::parse(MONKEYS.replace("by 23", "by 0").as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::BufRead;
+
+use aoc_common::{search, Answer, Error, Grid, Point, Result, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = ElevationMap;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let grid = Grid::parse(input, "elevation", |x| {
+            (x.is_ascii_lowercase() || x == 'S' || x == 'E').then_some(x)
+        })?;
+        Ok(ElevationMap { grid })
+    }
+
+    /// Shortest path from the start to the best signal
+    fn part_1(elevation_map: &Self::Input) -> Result<Answer> {
+        Ok((elevation_map.shortest_path(false)?.len() - 1).into())
+    }
+
+    /// Shortest path from any square at the lowest elevation to the best signal
+    fn part_2(elevation_map: &Self::Input) -> Result<Answer> {
+        Ok((elevation_map.shortest_path(true)?.len() - 1).into())
+    }
+}
+
+pub struct ElevationMap {
+    grid: Grid<char>,
+}
+
+impl ElevationMap {
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Every square on the shortest path to the best signal, from the start or, if
+    /// `from_lowest`, from whichever square at the lowest elevation is closest
+    pub fn shortest_path(&self, from_lowest: bool) -> Result<Vec<Point<i64>>> {
+        let is_start = |val| val == 'S' || (from_lowest && val == 'a');
+        let starts = self.grid.iter().filter(|(_, &val)| is_start(val));
+        let path = search::bfs_from(
+            starts.map(|(pos, _)| pos),
+            |&pos| self.get_neighbors(pos),
+            |&pos| self.grid[pos] == 'E',
+        );
+        match path {
+            Some(path) => Ok(path.nodes),
+            None => Err(Error::parse("there's no way to reach the best signal")),
+        }
+    }
+
+    fn get_neighbors(&self, start: Point<i64>) -> impl Iterator<Item = Point<i64>> + '_ {
+        let max_height = height(self.grid[start]) + 1;
+        self.grid
+            .neighbors4(start)
+            .filter(move |&n| height(self.grid[n]) <= max_height)
+    }
+}
+
+/// How high a square is, from 0 for `a` to 25 for `z`
+pub fn height(val: char) -> usize {
+    let character = match val {
+        'S' => 'a',
+        'E' => 'z',
+        _ => val,
+    };
+    character as usize - 97
+}
@@ -0,0 +1,103 @@
+use aoc_common::{input, Answer, Error, Line, Result, Solution};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt, io::BufRead};
+// For this once, I got very stuck on parsing and got a lot of help from: https://fasterthanli.me/series/advent-of-code-2022/part-13
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Node>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let nodes = Line::all(&input::read_lines(input)?)
+            .filter(|line| !line.text.is_empty())
+            .map(|line| {
+                serde_json::from_str::<Node>(line.text).map_err(|err| {
+                    let column = err.column().saturating_sub(1).min(line.text.len());
+                    let at = line.text.get(column..).unwrap_or(line.text);
+                    line.error(at, "not a valid packet")
+                })
+            })
+            .collect::<Result<Vec<Node>>>()?;
+        if nodes.len() % 2 != 0 {
+            return Err(Error::parse("packets must come in pairs"));
+        }
+        Ok(nodes)
+    }
+
+    /// Sum of valid message indices
+    fn part_1(nodes: &Self::Input) -> Result<Answer> {
+        let mut idx_sum = 0;
+        let mut idx = 0;
+        for pair in nodes.chunks(2) {
+            idx += 1;
+            let (left, right) = (pair.first().unwrap(), pair.get(1).unwrap());
+            if left < right {
+                idx_sum += idx;
+            }
+        }
+        Ok(idx_sum.into())
+    }
+
+    /// Product of divider indices
+    fn part_2(nodes: &Self::Input) -> Result<Answer> {
+        let mut nodes = nodes.clone();
+        let div_1 = Node::List(vec![Node::Value(2)]);
+        let div_2 = Node::List(vec![Node::Value(6)]);
+        nodes.push(div_1.clone());
+        nodes.push(div_2.clone());
+        nodes.sort();
+
+        let loc_1 = nodes.binary_search(&div_1).unwrap();
+        let loc_2 = nodes.binary_search(&div_2).unwrap();
+        Ok(((loc_1 + 1) * (loc_2 + 1)).into())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Node {
+    Value(i64),
+    List(Vec<Node>),
+}
+
+impl fmt::Debug for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(n) => write!(f, "{n}"),
+            Self::List(n) => f.debug_list().entries(n).finish(),
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Node::Value(l), Node::Value(r)) => l.cmp(r),
+            (l, r) => {
+                let r = match r {
+                    Node::Value(rr) => vec![Node::Value(*rr)],
+                    Node::List(v) => v.to_vec(),
+                };
+                let l = match l {
+                    Node::Value(ll) => vec![Node::Value(*ll)],
+                    Node::List(v) => v.to_vec(),
+                };
+
+                for (l, r) in l.iter().zip(r.iter()) {
+                    let v = l.cmp(r);
+                    if v != Ordering::Equal {
+                        return v;
+                    }
+                }
+                l.len().cmp(&r.len())
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    animate::{Bounds, Render, Simulation},
+    input, Answer, Compass, Grid, Line, Point, Result, Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Cave;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let rocks: Vec<Barrier> = Line::all(&input::read_lines(input)?)
+            .map(|line| Barrier::parse(&line))
+            .collect::<Result<_>>()?;
+        Ok(Cave::from_barriers(rocks))
+    }
+
+    /// Grains of sand that come to rest before sand starts falling into the abyss
+    fn part_1(cave: &Self::Input) -> Result<Answer> {
+        Ok(Sandfall::new(cave.clone(), false).run().into())
+    }
+
+    /// Grains of sand that come to rest on the floor before the source is blocked
+    fn part_2(cave: &Self::Input) -> Result<Answer> {
+        Ok(Sandfall::new(cave.clone(), true).run().into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    /// The floor is two below this, and without it sand would fall forever past it
+    lowest_rock: i64,
+    grid: Grid<Material>,
+}
+
+impl Cave {
+    /// A cave wide enough for the widest pile of sand the floor could hold
+    fn from_barriers(rocks: Vec<Barrier>) -> Self {
+        let source = Sandfall::SOURCE;
+        let pos = rocks.iter().flat_map(|x| &x.0);
+        let max_y = pos.clone().map(|pos| pos.y).max().unwrap_or(source.y) + 2;
+        let spread = max_y - source.y;
+        let min_x = pos
+            .clone()
+            .map(|pos| pos.x)
+            .fold(source.x - spread, i64::min);
+        let max_x = pos.map(|pos| pos.x).fold(source.x + spread, i64::max);
+
+        let mut cave = Cave::new(Point::new(min_x, 0), Point::new(max_x, max_y));
+        for barrier in rocks {
+            cave.insert_barrier(barrier)
+        }
+        cave
+    }
+
+    /// A cave of air from `origin` to `size` inclusive, with the floor along the bottom
+    fn new(origin: Point<i64>, size: Point<i64>) -> Self {
+        let width = (size.x - origin.x + 1) as usize;
+        let height = (size.y - origin.y + 1) as usize;
+        let mut grid = Grid::with_origin(origin, width, height, Material::Air);
+        for x in origin.x..size.x + 1 {
+            grid[Point::new(x, size.y)] = Material::Rock;
+        }
+        Cave {
+            lowest_rock: size.y - 2,
+            grid,
+        }
+    }
+
+    fn get(&self, pos: Point<i64>) -> Option<&Material> {
+        self.grid.get(pos)
+    }
+
+    fn set(&mut self, pos: Point<i64>, item: Material) {
+        self.grid[pos] = item;
+    }
+
+    /// A unit of sand always falls down one step if possible. If the tile immediately below is
+    /// blocked (by rock or sand), the unit of sand attempts to instead move diagonally one step
+    /// down and to the left. If that tile is blocked, the unit of sand attempts to instead move
+    /// diagonally one step down and to the right. Sand keeps moving as long as it is able to do so,
+    /// at each step trying to move down, then down-left, then down-right. If all three possible
+    /// destinations are blocked, the unit of sand comes to rest and no longer moves, at which point
+    /// the next unit of sand is created back at the source.
+    ///
+    /// Returns where the grain came to rest, or `None` if it fell past `abyss` or off the side.
+    fn advance_sand(&mut self, mut pos: Point<i64>, abyss: Option<i64>) -> Option<Point<i64>> {
+        while let Some(next) = self.try_advance(pos) {
+            if pos == next {
+                self.set(pos, Material::RestingSand);
+                return Some(pos);
+            }
+            if abyss.is_some_and(|abyss| next.y > abyss) {
+                self.set(pos, Material::Air);
+                return None;
+            }
+            self.set(pos, Material::Air);
+            self.set(next, Material::FallingSand);
+            pos = next;
+        }
+        None
+    }
+
+    fn try_advance(&self, pos: Point<i64>) -> Option<Point<i64>> {
+        for direction in [Compass::South, Compass::SouthWest, Compass::SouthEast] {
+            let next = pos.step(direction);
+            if let Material::Air = self.get(next)? {
+                return Some(next);
+            }
+        }
+        Some(pos)
+    }
+
+    fn insert_barrier(&mut self, barrier: Barrier) {
+        let mut rocks = barrier.0.iter().peekable();
+        while let Some(pos) = rocks.next() {
+            if let Some(next) = rocks.peek() {
+                if pos.x == next.x {
+                    let start = i64::min(pos.y, next.y);
+                    let end = i64::max(pos.y, next.y);
+                    for y in start..end + 1 {
+                        self.set(Point::new(pos.x, y), Material::Rock)
+                    }
+                } else if pos.y == next.y {
+                    let start = i64::min(pos.x, next.x);
+                    let end = i64::max(pos.x, next.x);
+                    for x in start..end + 1 {
+                        self.set(Point::new(x, pos.y), Material::Rock)
+                    }
+                } else {
+                    unreachable!("barriers are checked for straight lines when parsed")
+                }
+            };
+        }
+    }
+}
+
+/// Sand pouring into a cave a grain at a time, until it's blocked or falls into the abyss
+pub struct Sandfall {
+    cave: Cave,
+    /// Grains fall forever past here, unless there's a floor
+    abyss: Option<i64>,
+    grains: usize,
+    last: Option<Point<i64>>,
+    finished: bool,
+}
+
+impl Sandfall {
+    const SOURCE: Point<i64> = Point::new(500, 0);
+
+    pub fn new(mut cave: Cave, floor: bool) -> Self {
+        let abyss = (!floor).then_some(cave.lowest_rock);
+        cave.set(Sandfall::SOURCE, Material::FallingSand);
+        Sandfall {
+            cave,
+            abyss,
+            grains: 0,
+            last: None,
+            finished: false,
+        }
+    }
+
+    /// Pour sand until it stops, returning the number of grains that came to rest
+    fn run(mut self) -> usize {
+        while self.step() {}
+        self.grains
+    }
+}
+
+impl Simulation for Sandfall {
+    /// Drop the next grain from the source and let it come to rest
+    fn step(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        match self.cave.advance_sand(Sandfall::SOURCE, self.abyss) {
+            Some(rest) => {
+                self.grains += 1;
+                self.last = Some(rest);
+                self.finished = rest == Sandfall::SOURCE;
+            }
+            None => self.finished = true,
+        }
+        true
+    }
+}
+
+impl Render for Sandfall {
+    fn bounds(&self) -> Bounds {
+        let grid = &self.cave.grid;
+        let size = Point::new(grid.width() as i64, grid.height() as i64);
+        Bounds::new(grid.origin(), grid.origin() + size - Point::new(1, 1))
+    }
+
+    fn cell(&self, pos: Point<i64>) -> char {
+        self.cave.get(pos).map_or('.', Material::symbol)
+    }
+
+    fn focus(&self) -> Option<Point<i64>> {
+        Some(self.last.unwrap_or(Sandfall::SOURCE))
+    }
+
+    fn status(&self) -> String {
+        format!("{} grains at rest", self.grains)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Material {
+    Rock,
+    Air,
+    FallingSand,
+    RestingSand,
+}
+
+impl Material {
+    fn symbol(&self) -> char {
+        match self {
+            Material::Rock => '#',
+            Material::Air => '.',
+            Material::FallingSand => '+',
+            Material::RestingSand => 'o',
+        }
+    }
+}
+
+/// Parse `point`, a part of `line` like `498,4`, which can't be above the source of the sand
+fn parse_point<'a>(line: &Line<'a>, point: &'a str) -> Result<Point<i64>> {
+    let (x, y) = line.split_once(point, ",")?;
+    let y = line.parse(y)?;
+    if y < 0 {
+        return Err(line.error(
+            point,
+            format!("{:?} is above the source of the sand", point),
+        ));
+    }
+    Ok(Point::new(line.parse(x)?, y))
+}
+
+/// A path of rock, straight horizontal or vertical lines from each point to the next
+#[derive(Debug, Clone)]
+struct Barrier(Vec<Point<i64>>);
+
+impl Barrier {
+    fn parse(line: &Line) -> Result<Barrier> {
+        let mut points: Vec<Point<i64>> = Vec::new();
+        for point in line.text.split(" -> ") {
+            let pos = parse_point(line, point)?;
+            if let Some(last) = points.last() {
+                if last.x != pos.x && last.y != pos.y {
+                    return Err(line.error(
+                        point,
+                        format!("{:?} isn't in a straight line from the point before", point),
+                    ));
+                }
+            }
+            points.push(pos);
+        }
+        Ok(Barrier(points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day14, Sandfall, Solution};
+
+    #[test]
+    fn barriers() {
+        assert!(Day14::parse("498,4 -> 498,6 -> 496,6".as_bytes()).is_ok());
+        // Diagonal lines
+        assert!(Day14::parse("498,4 -> 500,6".as_bytes()).is_err());
+        // Above the source
+        assert!(Day14::parse("498,-1 -> 498,6".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn sand_away_from_rocks() {
+        let grains = |input: &str, floor| {
+            let cave = Day14::parse(input.as_bytes()).unwrap();
+            Sandfall::new(cave, floor).run()
+        };
+        // With nothing under it the sand piles up on the floor, one row wider each step down
+        assert_eq!((grains("", false), grains("", true)), (0, 4));
+        assert_eq!(grains("10,5 -> 20,5", false), 0);
+        assert_eq!(grains("10,5 -> 20,5", true), 49);
+    }
+}
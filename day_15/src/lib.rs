@@ -0,0 +1,258 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+    ops::RangeInclusive,
+};
+
+use aoc_common::{
+    animate::{Bounds, Render},
+    input, Answer, Error, IntervalSet, Line, Point, Result, Solution,
+};
+use regex::Regex;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Cave;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        Cave::new(input::read_lines(input)?)
+    }
+
+    /// The example asks about row 10 and coordinates up to 20 instead
+    fn use_example(cave: &mut Self::Input) {
+        cave.row = 10;
+        cave.bound = 20;
+    }
+
+    /// Positions that cannot contain a beacon in the row of interest
+    fn part_1(cave: &Self::Input) -> Result<Answer> {
+        Ok(cave.get_row(cave.row).len().into())
+    }
+
+    /// Tuning frequency of the only position the distress beacon could be at
+    fn part_2(cave: &Self::Input) -> Result<Answer> {
+        let max = cave.bound;
+        let loc = cave
+            .search(&Point::new(max, max))
+            .ok_or_else(|| Error::parse("every position is covered by some sensor"))?;
+        Ok(tuning_frequency(&loc).into())
+    }
+}
+
+pub struct Cave {
+    origin: Point<i64>,
+    max: Point<i64>,
+    sensors: HashMap<Point<i64>, Sensor>,
+    beacons: HashSet<Point<i64>>,
+    /// The row part 1 asks about
+    row: i64,
+    /// The distress beacon's coordinates are from 0 up to this
+    bound: i64,
+}
+
+impl Cave {
+    fn new(lines: Vec<String>) -> Result<Cave> {
+        let (mut min_x, mut min_y) = (i64::MAX, i64::MAX);
+        let (mut max_x, mut max_y) = (i64::MIN, i64::MIN);
+
+        let mut sensors = HashMap::new();
+        let mut beacons = HashSet::new();
+        for line in Line::all(&lines) {
+            let sensor = Sensor::parse(&line)?;
+            let distance = sensor.beacon_distance();
+
+            min_x = i64::min(min_x, sensor.pos.x - distance);
+            min_y = i64::min(min_y, sensor.pos.y - distance);
+            max_x = i64::max(max_x, sensor.pos.x + distance);
+            max_y = i64::max(max_y, sensor.pos.y + distance);
+            let beacon = sensor.closest_beacon;
+            beacons.insert(beacon);
+            sensors.insert(sensor.pos, sensor);
+        }
+        if sensors.is_empty() {
+            return Err(Error::parse("expected at least one sensor"));
+        }
+
+        Ok(Cave {
+            origin: Point::new(min_x, min_y),
+            max: Point::new(max_x, max_y),
+            sensors,
+            beacons,
+            row: 2000000,
+            bound: 4000000,
+        })
+    }
+
+    /// The one position up to `max` that no sensor covers, if there is one
+    fn search(&self, max: &Point<i64>) -> Option<Point<i64>> {
+        let candiates = self.sensors.values().clone();
+
+        for s in candiates {
+            for pos in s.edges() {
+                if pos.x >= 0
+                    && pos.x <= max.x
+                    && pos.y >= 0
+                    && pos.y <= max.y
+                    && !self.sensors.contains_key(&pos)
+                    && !self.beacons.contains(&pos)
+                {
+                    let mut open = true;
+                    for s in self.sensors.values() {
+                        if s.is_within_coverage(&pos) {
+                            open = false;
+                        }
+                    }
+                    if open {
+                        return Some(pos);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The x coordinates in row `y` that some sensor covers, other than where the sensors and
+    /// beacons themselves are
+    fn get_row(&self, y: i64) -> IntervalSet<i64> {
+        let mut row: IntervalSet<i64> = self
+            .sensors
+            .values()
+            .filter_map(|s| s.row_coverage(y))
+            .collect();
+        let occupied = self.sensors.keys().chain(self.beacons.iter());
+        for pos in occupied.filter(|pos| pos.y == y) {
+            row.remove(pos.x..=pos.x);
+        }
+        row
+    }
+}
+
+/// Sensors and beacons, over `#` wherever a sensor rules out a beacon
+impl Render for Cave {
+    fn bounds(&self) -> Bounds {
+        Bounds::new(self.origin, self.max)
+    }
+
+    fn cell(&self, pos: Point<i64>) -> char {
+        if self.sensors.contains_key(&pos) {
+            'S'
+        } else if self.beacons.contains(&pos) {
+            'B'
+        } else if self.sensors.values().any(|s| s.is_within_coverage(&pos)) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Tuning frequency, which can be found by multiplying its x coordinate by 4000000 and then adding its y coordinate.
+fn tuning_frequency(pos: &Point<i64>) -> i64 {
+    (pos.x * 4000000) + pos.y
+}
+
+#[derive(Debug, Clone)]
+struct Sensor {
+    pos: Point<i64>,
+    closest_beacon: Point<i64>,
+}
+
+impl Sensor {
+    fn parse(line: &Line) -> Result<Self> {
+        let re = Regex::new(
+            r"^Sensor at x=(-*\d*), y=(-*\d*): closest beacon is at x=(-*\d*), y=(-*\d*)",
+        )
+        .unwrap();
+        if let Some(cap) = re.captures(line.text) {
+            let coordinate = |i| line.parse(cap.get(i).unwrap().as_str());
+            Ok(Sensor {
+                pos: Point::new(coordinate(1)?, coordinate(2)?),
+                closest_beacon: Point::new(coordinate(3)?, coordinate(4)?),
+            })
+        } else {
+            Err(line.invalid("sensor report"))
+        }
+    }
+
+    fn beacon_distance(&self) -> i64 {
+        self.pos.manhattan(&self.closest_beacon)
+    }
+
+    fn is_within_coverage(&self, pos: &Point<i64>) -> bool {
+        self.pos.manhattan(pos) <= self.beacon_distance()
+    }
+
+    /// The x coordinates this sensor covers in row `y`, if it reaches that far
+    fn row_coverage(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let reach = self.beacon_distance() - (self.pos.y - y).abs();
+        (reach >= 0).then(|| self.pos.x - reach..=self.pos.x + reach)
+    }
+
+    fn edges(&self) -> HashSet<Point<i64>> {
+        let mut edges = HashSet::new();
+        let dist = self.beacon_distance() + 1;
+        let (x, y) = (self.pos.x, self.pos.y);
+        for vertical in 0..dist + 1 {
+            let horizontal = dist - vertical;
+            edges.insert(Point::new(x - horizontal, y + vertical));
+            edges.insert(Point::new(x - horizontal, y - vertical));
+            edges.insert(Point::new(x + horizontal, y + vertical));
+            edges.insert(Point::new(x + horizontal, y - vertical));
+        }
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Point;
+
+    use crate::{Day15, Sensor, Solution};
+
+    #[test]
+    fn no_beacon() {
+        assert!(Day15::parse("".as_bytes()).is_err());
+        // One sensor covering everywhere the distress beacon could be
+        let input = "Sensor at x=10, y=10: closest beacon is at x=10, y=40";
+        let mut cave = Day15::parse(input.as_bytes()).unwrap();
+        Day15::use_example(&mut cave);
+        assert!(Day15::part_2(&cave).is_err());
+    }
+
+    #[test]
+    fn example_questions() {
+        // Far enough out to look like the real puzzle, but asked about like the example
+        let input = "Sensor at x=5000, y=10: closest beacon is at x=5000, y=12";
+        let mut cave = Day15::parse(input.as_bytes()).unwrap();
+        assert_eq!(Day15::part_1(&cave).unwrap(), 0.into());
+        Day15::use_example(&mut cave);
+        assert_eq!(Day15::part_1(&cave).unwrap(), 4.into());
+    }
+
+    #[test]
+    fn edges() {
+        let test = Sensor {
+            pos: Point::new(0, 0),
+            closest_beacon: Point::new(1, 1),
+        };
+        let set = vec![
+            Point::new(3, 0),
+            Point::new(2, 1),
+            Point::new(1, 2),
+            Point::new(0, 3),
+            Point::new(-1, 2),
+            Point::new(-2, 1),
+            Point::new(-3, 0),
+            Point::new(-2, -1),
+            Point::new(-1, -2),
+            Point::new(0, -3),
+            Point::new(1, -2),
+            Point::new(2, -1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(test.edges(), set);
+    }
+}
@@ -0,0 +1,122 @@
+// Still a work in progress: neither part is solved yet.
+#![allow(dead_code)]
+
+use std::{collections::HashMap, io::BufRead};
+
+use aoc_common::{search, Error, Line, Result, Solution};
+use regex::Regex;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Cave;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let mut nodes = HashMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let node = Node::parse(&Line::new(i + 1, &line?))?;
+            nodes.insert(node.name.clone(), node);
+        }
+        Cave::new(nodes)
+    }
+}
+
+/// The valves worth opening (those with any flow) and how far apart they are. Valves that
+/// don't flow only matter for how long it takes to walk past them, so they're left out.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    rates: Vec<usize>,
+    /// Minutes it takes to walk from each valve worth opening (followed by the start) to each
+    /// valve worth opening, or `usize::MAX` if there's no way there
+    distances: Vec<Vec<usize>>,
+}
+
+impl Cave {
+    fn new(nodes: HashMap<String, Node>) -> Result<Self> {
+        if !nodes.contains_key("AA") {
+            return Err(Error::parse("there's no valve AA to start at"));
+        }
+        let mut valves: Vec<&Node> = nodes.values().filter(|x| x.rate > 0).collect();
+        valves.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let neighbors = |name: &&str| {
+            nodes
+                .get(*name)
+                .into_iter()
+                .flat_map(|x| x.neighbors.iter().map(String::as_str))
+        };
+        let starts = valves.iter().map(|x| x.name.as_str()).chain(["AA"]);
+        let distances = starts
+            .map(|from| {
+                valves
+                    .iter()
+                    .map(|to| {
+                        search::bfs(from, neighbors, |&x| x == to.name)
+                            .map_or(usize::MAX, |path| path.cost)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Cave {
+            rates: valves.iter().map(|x| x.rate).collect(),
+            distances,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    name: String,
+    rate: usize,
+    neighbors: Vec<String>,
+}
+
+impl Node {
+    fn parse(line: &Line) -> Result<Self> {
+        // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+
+        let re = Regex::new(r"^Valve (.*) has flow rate=(\d*); tunnels* leads* to valves* (.*)")
+            .unwrap();
+        if let Some(cap) = re.captures(line.text) {
+            let neighbors = cap
+                .get(3)
+                .unwrap()
+                .as_str()
+                .split(", ")
+                .map(|x| x.to_string())
+                .collect();
+            Ok(Node {
+                name: cap.get(1).unwrap().as_str().to_string(),
+                rate: line.parse(cap.get(2).unwrap().as_str())?,
+                neighbors,
+            })
+        } else {
+            Err(line.invalid("valve report"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cave, Node};
+
+    #[test]
+    fn distances() {
+        let node = |name: &str, rate, neighbors: &[&str]| Node {
+            name: name.to_string(),
+            rate,
+            neighbors: neighbors.iter().map(|x| x.to_string()).collect(),
+        };
+        let nodes = [
+            node("AA", 0, &["BB"]),
+            node("BB", 13, &["CC", "AA"]),
+            node("CC", 2, &["BB"]),
+        ];
+        let cave = Cave::new(nodes.into_iter().map(|x| (x.name.clone(), x)).collect()).unwrap();
+        assert_eq!(cave.rates, vec![13, 2]);
+        // From BB, CC and then the start to BB and CC
+        assert_eq!(cave.distances, vec![vec![0, 1], vec![1, 0], vec![1, 2]]);
+    }
+}
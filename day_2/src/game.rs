@@ -0,0 +1,237 @@
+use aoc_common::{Error, Result};
+
+/// One of the moves in a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub name: String,
+    /// The letter for it in the opponent's column of a strategy guide
+    pub opponent: char,
+    /// The letter for it in the second column, when that's read as moves
+    pub response: char,
+    /// Points for playing it
+    pub score: i32,
+}
+
+impl Move {
+    pub fn new(name: &str, opponent: char, response: char, score: i32) -> Self {
+        Move {
+            name: name.to_string(),
+            opponent,
+            response,
+            score,
+        }
+    }
+}
+
+/// A move, as where it is in its `Ruleset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Action(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Win, Outcome::Draw, Outcome::Lose];
+
+    /// X to lose, Y to draw and Z to win, whatever the game
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'X' => Some(Outcome::Lose),
+            'Y' => Some(Outcome::Draw),
+            'Z' => Some(Outcome::Win),
+            _ => None,
+        }
+    }
+
+    // 0 if you lost, 3 if the round was a draw, and 6 if you won
+    pub fn score(&self) -> i32 {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Lose => 0,
+        }
+    }
+}
+
+/// The moves of a game, and which of them beats which
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    moves: Vec<Move>,
+    /// `beats[i][j]` is whether move `i` beats move `j`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Ruleset {
+    /// A game where `beats` lists which move beats which, as (winner, loser) names. Of any two
+    /// different moves, exactly one has to beat the other.
+    pub fn new(moves: Vec<Move>, beats: &[(&str, &str)]) -> Result<Self> {
+        let index = |name: &str| {
+            moves
+                .iter()
+                .position(|x| x.name == name)
+                .ok_or_else(|| Error::parse(format!("there's no move called {:?}", name)))
+        };
+        let mut table = vec![vec![false; moves.len()]; moves.len()];
+        for &(winner, loser) in beats {
+            let (i, j) = (index(winner)?, index(loser)?);
+            if i == j {
+                return Err(Error::parse(format!("{} can't beat itself", winner)));
+            }
+            table[i][j] = true;
+        }
+
+        for (i, a) in moves.iter().enumerate() {
+            for (j, b) in moves.iter().enumerate().take(i) {
+                if table[i][j] == table[j][i] {
+                    return Err(Error::parse(format!(
+                        "exactly one of {} and {} has to beat the other",
+                        b.name, a.name
+                    )));
+                }
+                if a.opponent == b.opponent || a.response == b.response {
+                    return Err(Error::parse(format!(
+                        "{} and {} have the same letter",
+                        b.name, a.name
+                    )));
+                }
+            }
+        }
+        Ok(Ruleset {
+            moves,
+            beats: table,
+        })
+    }
+
+    /// A game whose moves go round in a cycle, each beating the moves an odd number of places
+    /// before it. That needs an odd number of moves, and then every move beats exactly half of
+    /// the others.
+    pub fn cyclic(moves: Vec<Move>) -> Result<Self> {
+        let n = moves.len();
+        if n.is_multiple_of(2) {
+            return Err(Error::parse(format!(
+                "a cyclic game needs an odd number of moves, not {}",
+                n
+            )));
+        }
+        let names: Vec<String> = moves.iter().map(|x| x.name.clone()).collect();
+        let mut beats = Vec::new();
+        for i in 0..n {
+            for j in 0..n {
+                if (i + n - j) % n % 2 == 1 {
+                    beats.push((names[i].as_str(), names[j].as_str()));
+                }
+            }
+        }
+        Ruleset::new(moves, &beats)
+    }
+
+    /// Rock (`A`/`X`) beats Scissors, Paper (`B`/`Y`) beats Rock and Scissors (`C`/`Z`) beats
+    /// Paper. They score 1, 2 and 3.
+    pub fn rock_paper_scissors() -> Self {
+        Ruleset::cyclic(vec![
+            Move::new("Rock", 'A', 'X', 1),
+            Move::new("Paper", 'B', 'Y', 2),
+            Move::new("Scissors", 'C', 'Z', 3),
+        ])
+        .unwrap()
+    }
+
+    /// Rock, Paper and Scissors, plus Spock (`D`/`V`) and Lizard (`E`/`W`) scoring 4 and 5.
+    /// Spock smashes Scissors and vaporizes Rock, Lizard poisons Spock and eats Paper, Paper
+    /// disproves Spock, Scissors decapitate Lizard and Rock crushes it.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Ruleset::cyclic(vec![
+            Move::new("Rock", 'A', 'X', 1),
+            Move::new("Paper", 'B', 'Y', 2),
+            Move::new("Scissors", 'C', 'Z', 3),
+            Move::new("Spock", 'D', 'V', 4),
+            Move::new("Lizard", 'E', 'W', 5),
+        ])
+        .unwrap()
+    }
+
+    pub fn actions(&self) -> impl Iterator<Item = Action> {
+        (0..self.moves.len()).map(Action)
+    }
+
+    pub fn get(&self, action: Action) -> &Move {
+        &self.moves[action.0]
+    }
+
+    /// Points for playing `action`
+    pub fn score(&self, action: Action) -> i32 {
+        self.get(action).score
+    }
+
+    /// How the round ends when you play `action` against `opponent`
+    pub fn outcome(&self, action: Action, opponent: Action) -> Outcome {
+        if self.beats[action.0][opponent.0] {
+            Outcome::Win
+        } else if self.beats[opponent.0][action.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The move to make against `opponent` for the round to end in `outcome`, or the best
+    /// scoring one if several would. `None` if none would.
+    pub fn play(&self, opponent: Action, outcome: Outcome) -> Option<Action> {
+        self.actions()
+            .filter(|&action| self.outcome(action, opponent) == outcome)
+            .max_by_key(|&action| self.score(action))
+    }
+
+    /// The move with `letter` in the opponent's column
+    pub fn by_opponent(&self, letter: char) -> Option<Action> {
+        self.actions().find(|&x| self.get(x).opponent == letter)
+    }
+
+    /// The move with `letter` in the second column, read as moves
+    pub fn by_response(&self, letter: char) -> Option<Action> {
+        self.actions().find(|&x| self.get(x).response == letter)
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Ruleset::rock_paper_scissors()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{Action, Move, Outcome, Ruleset};
+
+    #[test]
+    fn rules() {
+        let rps = Ruleset::rock_paper_scissors();
+        let (rock, paper, scissors) = (Action(0), Action(1), Action(2));
+        assert_eq!(rps.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rps.outcome(paper, scissors), Outcome::Lose);
+        assert_eq!(rps.play(scissors, Outcome::Win), Some(rock));
+
+        let rpsls = Ruleset::rock_paper_scissors_lizard_spock();
+        let (spock, lizard) = (Action(3), Action(4));
+        assert_eq!(rpsls.outcome(spock, scissors), Outcome::Win);
+        assert_eq!(rpsls.outcome(lizard, paper), Outcome::Win);
+        assert_eq!(rpsls.outcome(rock, lizard), Outcome::Win);
+        // Scissors and Lizard both beat Paper, and Lizard scores more
+        assert_eq!(rpsls.play(paper, Outcome::Win), Some(lizard));
+        for a in rpsls.actions() {
+            let wins = rpsls
+                .actions()
+                .filter(|&b| rpsls.outcome(a, b) == Outcome::Win);
+            assert_eq!(wins.count(), 2);
+        }
+
+        let even = vec![Move::new("Up", 'A', 'X', 1), Move::new("Down", 'B', 'Y', 2)];
+        assert!(Ruleset::cyclic(even.clone()).is_err());
+        assert!(Ruleset::new(even.clone(), &[]).is_err());
+        assert!(Ruleset::new(even, &[("Up", "Down")]).is_ok());
+    }
+}
@@ -0,0 +1,240 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, Error, Line, Result, Solution};
+use serde::Serialize;
+
+mod game;
+
+pub use game::{Action, Move, Outcome, Ruleset};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Guide;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        Guide::parse(input, Ruleset::default())
+    }
+
+    /// Total score when the second column is the move to make
+    fn part_1(guide: &Self::Input) -> Result<Answer> {
+        Ok(guide.total_score(Strategy::Moves)?.into())
+    }
+
+    /// Total score when the second column is the outcome to aim for
+    fn part_2(guide: &Self::Input) -> Result<Answer> {
+        Ok(guide.total_score(Strategy::Outcomes)?.into())
+    }
+}
+
+/// A strategy guide, and the game it's for
+#[derive(Debug, Clone)]
+pub struct Guide {
+    pub rules: Ruleset,
+    pub rounds: Vec<Round>,
+}
+
+/// One line of the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub line: usize,
+    pub opponent: Action,
+    /// The letter in the second column, which means something different to each `Strategy`
+    pub response: char,
+}
+
+impl Guide {
+    /// Read a guide for a game played by `rules`. The first column is the opponent's move, and
+    /// the second either one of the ruleset's response letters or `X`, `Y` or `Z`.
+    pub fn parse(input: impl BufRead, rules: Ruleset) -> Result<Self> {
+        let mut rounds = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let text = line?;
+            let line = Line::new(i + 1, &text);
+            let (left, right) = line.split_once(&text, " ")?;
+            let opponent = letter(left)
+                .and_then(|x| rules.by_opponent(x))
+                .ok_or_else(|| line.error(left, format!("{:?} is not a valid Action", left)))?;
+            let response = letter(right)
+                .filter(|&x| Outcome::from_letter(x).is_some() || rules.by_response(x).is_some())
+                .ok_or_else(|| line.error(right, format!("{:?} is not a valid response", right)))?;
+            rounds.push(Round {
+                line: line.number,
+                opponent,
+                response,
+            });
+        }
+        Ok(Guide { rules, rounds })
+    }
+
+    /// Score for following the guide, reading it with `strategy`
+    pub fn total_score(&self, strategy: Strategy) -> Result<i32> {
+        let mut total_score = 0;
+        for round in &self.rounds {
+            let (action, outcome) = strategy.round(&self.rules, round)?;
+            total_score += self.rules.score(action) + outcome.score();
+        }
+        Ok(total_score)
+    }
+}
+
+/// How one round of a guide could have gone
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoundAnalysis {
+    pub line: usize,
+    pub opponent: String,
+    pub best_move: String,
+    pub best: i32,
+    pub worst: i32,
+    /// The average score of a player picking any move at random
+    pub expected: f64,
+    /// The guide's score with each `Strategy`
+    pub moves: i32,
+    pub outcomes: i32,
+}
+
+/// How well anyone could do against the opponent's moves in a guide, and how well the guide
+/// does, over every round
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Analysis {
+    pub best: i32,
+    pub worst: i32,
+    pub expected: f64,
+    pub moves: i32,
+    pub outcomes: i32,
+    pub rounds: Vec<RoundAnalysis>,
+}
+
+/// Score every way of playing against the opponent's moves in `guide`
+pub fn analyze(guide: &Guide) -> Result<Analysis> {
+    let rules = &guide.rules;
+    let mut rounds = Vec::with_capacity(guide.rounds.len());
+    for round in &guide.rounds {
+        let opponent = round.opponent;
+        let score = |action| rules.score(action) + rules.outcome(action, opponent).score();
+        let scores: Vec<i32> = rules.actions().map(score).collect();
+        // The best move is always the best scoring one for some outcome
+        let best_move = Outcome::ALL
+            .into_iter()
+            .filter_map(|outcome| rules.play(opponent, outcome))
+            .max_by_key(|&action| score(action))
+            .unwrap();
+        let as_read = |strategy: Strategy| {
+            let (action, outcome) = strategy.round(rules, round)?;
+            Ok::<_, Error>(rules.score(action) + outcome.score())
+        };
+        rounds.push(RoundAnalysis {
+            line: round.line,
+            opponent: rules.get(opponent).name.clone(),
+            best_move: rules.get(best_move).name.clone(),
+            best: score(best_move),
+            worst: scores.iter().copied().min().unwrap(),
+            expected: scores.iter().sum::<i32>() as f64 / scores.len() as f64,
+            moves: as_read(Strategy::Moves)?,
+            outcomes: as_read(Strategy::Outcomes)?,
+        });
+    }
+
+    Ok(Analysis {
+        best: rounds.iter().map(|x| x.best).sum(),
+        worst: rounds.iter().map(|x| x.worst).sum(),
+        expected: rounds.iter().map(|x| x.expected).sum(),
+        moves: rounds.iter().map(|x| x.moves).sum(),
+        outcomes: rounds.iter().map(|x| x.outcomes).sum(),
+        rounds,
+    })
+}
+
+/// `text` if it's a single character
+fn letter(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    chars.next().filter(|_| chars.next().is_none())
+}
+
+/// The two ways of reading the second column of the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The moves to make: X, Y and Z are Rock, Paper and Scissors
+    Moves,
+    /// How the round needs to end: X, Y and Z are lose, draw or win
+    Outcomes,
+}
+
+impl Strategy {
+    /// The move to make in `round`, and how the round ends
+    pub fn round(&self, rules: &Ruleset, round: &Round) -> Result<(Action, Outcome)> {
+        let opponent = round.opponent;
+        match self {
+            Strategy::Moves => {
+                let action = rules.by_response(round.response).ok_or_else(|| {
+                    Error::parse(format!(
+                        "{:?} on line {} is not a move",
+                        round.response, round.line
+                    ))
+                })?;
+                Ok((action, rules.outcome(action, opponent)))
+            }
+            Strategy::Outcomes => {
+                let outcome = Outcome::from_letter(round.response).ok_or_else(|| {
+                    Error::parse(format!(
+                        "{:?} on line {} is not an outcome",
+                        round.response, round.line
+                    ))
+                })?;
+                let action = rules.play(opponent, outcome).ok_or_else(|| {
+                    Error::parse(format!(
+                        "nothing gets a {:?} against {} on line {}",
+                        outcome,
+                        rules.get(opponent).name,
+                        round.line
+                    ))
+                })?;
+                Ok((action, outcome))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analyze, Day2, Guide, Ruleset, Solution, Strategy};
+
+    #[test]
+    fn analysis() {
+        let guide = Day2::parse("A Y\nB X\nC Z".as_bytes()).unwrap();
+        let analysis = analyze(&guide).unwrap();
+        // Paper, Scissors and Rock win every round, and Scissors, Rock and Paper lose them
+        assert_eq!((analysis.best, analysis.worst), (8 + 9 + 7, 3 + 1 + 2));
+        assert_eq!(analysis.expected, 15.0);
+        assert_eq!((analysis.moves, analysis.outcomes), (15, 12));
+        let round = &analysis.rounds[1];
+        assert_eq!(
+            (round.opponent.as_str(), round.best_move.as_str()),
+            ("Paper", "Scissors")
+        );
+        assert_eq!((round.best, round.worst, round.expected), (9, 1, 5.0));
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules = Ruleset::rock_paper_scissors_lizard_spock();
+        let guide = Guide::parse("A Y\nD X\nE Z\nB Z".as_bytes(), rules).unwrap();
+        // Paper covers Rock, Spock vaporizes Rock, and Scissors cut Lizard and Paper
+        assert_eq!(guide.total_score(Strategy::Moves).unwrap(), 8 + 1 + 9 + 9);
+        // Rock draws, Scissors lose to Spock, and the best scoring win is Scissors against
+        // Lizard and Lizard against Paper
+        assert_eq!(
+            guide.total_score(Strategy::Outcomes).unwrap(),
+            4 + 3 + 9 + 11
+        );
+
+        let guide = Guide::parse("A V".as_bytes(), Ruleset::default());
+        assert!(guide.is_err());
+        let guide = Guide::parse(
+            "A V".as_bytes(),
+            Ruleset::rock_paper_scissors_lizard_spock(),
+        );
+        assert!(guide.unwrap().total_score(Strategy::Outcomes).is_err());
+    }
+}
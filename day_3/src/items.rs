@@ -0,0 +1,138 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr},
+};
+
+use aoc_common::{Line, Result};
+
+/// A set of kinds of item, as a bit per priority: bit 1 is `a`, bit 26 `z`, bit 27 `A` and bit
+/// 52 `Z`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+    /// Every kind of item there is
+    pub const ALL: ItemSet = ItemSet(((1 << 52) - 1) << 1);
+
+    /// The items in `part` of `line`, which has to be all letters
+    pub fn parse(line: &Line, part: &str) -> Result<Self> {
+        let mut set = ItemSet::EMPTY;
+        for (i, c) in part.char_indices() {
+            let priority = u8::try_from(c).ok().and_then(priority).ok_or_else(|| {
+                line.error(
+                    &part[i..i + c.len_utf8()],
+                    format!("{:?} is not an item", c),
+                )
+            })?;
+            set.insert(priority);
+        }
+        Ok(set)
+    }
+
+    /// The items in every one of `sets`. With no sets at all, that's every item.
+    pub fn common(sets: impl IntoIterator<Item = ItemSet>) -> Self {
+        sets.into_iter()
+            .fold(ItemSet::ALL, |common, set| common & set)
+    }
+
+    pub fn insert(&mut self, priority: u32) {
+        self.0 |= 1 << priority;
+    }
+
+    pub fn contains(&self, priority: u32) -> bool {
+        self.0 & (1 << priority) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priority of every item in the set, lowest first
+    pub fn iter(&self) -> impl Iterator<Item = u32> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let priority = bits.trailing_zeros();
+            (bits != 0).then(|| {
+                bits &= bits - 1;
+                priority
+            })
+        })
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+}
+
+/// The items as letters, `a` to `z` then `A` to `Z`
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|x| write!(f, "{}", item(x)))
+    }
+}
+
+/// 1 to 26 for `a` to `z` and 27 to 52 for `A` to `Z`, or `None` for anything else
+pub fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some((item - b'a') as u32 + 1),
+        b'A'..=b'Z' => Some((item - b'A') as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item with `priority`, from 1 to 52
+pub fn item(priority: u32) -> char {
+    if priority <= 26 {
+        (b'a' + (priority - 1) as u8) as char
+    } else {
+        (b'A' + (priority - 27) as u8) as char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Line;
+
+    use crate::items::{item, priority, ItemSet};
+
+    #[test]
+    fn item_sets() {
+        assert_eq!((priority(b'a'), priority(b'z')), (Some(1), Some(26)));
+        assert_eq!((priority(b'A'), priority(b'Z')), (Some(27), Some(52)));
+        assert_eq!(priority(b'1'), None);
+        assert!((1..=52).all(|x| priority(item(x) as u8) == Some(x)));
+
+        let line = Line::new(1, "vJrwpWtwJgWrhcsFMMfFFhFp");
+        let (left, right) = line.text.split_at(12);
+        let (left, right) = (
+            ItemSet::parse(&line, left).unwrap(),
+            ItemSet::parse(&line, right).unwrap(),
+        );
+        assert_eq!((left & right).iter().collect::<Vec<_>>(), vec![16]);
+        assert_eq!((left & right).to_string(), "p");
+        assert_eq!(left.len(), 8);
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(
+            ItemSet::common([left, right, ItemSet::EMPTY]),
+            ItemSet::EMPTY
+        );
+        assert!(ItemSet::parse(&line, "ab-c").is_err());
+    }
+}
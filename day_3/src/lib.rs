@@ -0,0 +1,190 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, Error, Line, Result, Solution};
+use serde::Serialize;
+
+mod items;
+
+pub use items::{item, priority, ItemSet};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let mut rucksacks = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let text = line?;
+            rucksacks.push(Rucksack::parse(&Line::new(i + 1, &text))?);
+        }
+        Ok(rucksacks)
+    }
+
+    /// Sum of the priorities of the item in both compartments of each rucksack
+    fn part_1(rucksacks: &Self::Input) -> Result<Answer> {
+        let mut total_value = 0;
+        for rucksack in rucksacks {
+            total_value += duplicate([rucksack.left, rucksack.right], || {
+                format!(
+                    "both compartments of the rucksack on line {}",
+                    rucksack.line
+                )
+            })?;
+        }
+        Ok(total_value.into())
+    }
+
+    /// Sum of the priorities of the badge shared by each group of three elves
+    fn part_2(rucksacks: &Self::Input) -> Result<Answer> {
+        let mut total_value = 0;
+        for group in groups(rucksacks, 3)? {
+            total_value += duplicate(group.iter().map(Rucksack::items), || describe(group))?;
+        }
+        Ok(total_value.into())
+    }
+}
+
+/// The items in an elf's rucksack, split into its two compartments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub line: usize,
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    fn parse(line: &Line) -> Result<Self> {
+        let text = line.text;
+        let middle = text.len() / 2;
+        if !text.len().is_multiple_of(2) || !text.is_char_boundary(middle) {
+            // Point at anything that isn't an item first, as that's more likely the problem
+            ItemSet::parse(line, text)?;
+            return Err(line.error(text, "a rucksack needs two compartments the same size"));
+        }
+        let (left, right) = text.split_at(middle);
+        let (left, right) = (ItemSet::parse(line, left)?, ItemSet::parse(line, right)?);
+        Ok(Rucksack {
+            line: line.number,
+            left,
+            right,
+        })
+    }
+
+    /// Every item in either compartment
+    pub fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+}
+
+/// The rucksacks split into groups of `size` elves in a row. It's an error for the last group to
+/// be short.
+pub fn groups(rucksacks: &[Rucksack], size: usize) -> Result<impl Iterator<Item = &[Rucksack]>> {
+    if size == 0 {
+        return Err(Error::parse("groups need at least one elf"));
+    }
+    let short = rucksacks.len() % size;
+    if short != 0 {
+        let first = &rucksacks[rucksacks.len() - short];
+        return Err(Error::parse(format!(
+            "the last group, from line {}, only has {} of {} rucksacks",
+            first.line, short, size
+        )));
+    }
+    Ok(rucksacks.chunks(size))
+}
+
+/// Where a group's rucksacks are, for errors
+fn describe(group: &[Rucksack]) -> String {
+    match group {
+        [one] => format!("the rucksack on line {}", one.line),
+        [first, .., last] => format!("every rucksack on lines {} to {}", first.line, last.line),
+        [] => "no rucksacks".to_string(),
+    }
+}
+
+/// The items a group of elves could have as its badge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Badge {
+    /// Lines of the group's first and last rucksack
+    pub from: usize,
+    pub to: usize,
+    /// Every item all of the group carries. There should be exactly one, the badge.
+    pub candidates: String,
+    pub badge: Option<char>,
+    pub priority: Option<u32>,
+}
+
+/// The possible badges of each group of `size` elves
+pub fn badges(rucksacks: &[Rucksack], size: usize) -> Result<Vec<Badge>> {
+    Ok(groups(rucksacks, size)?
+        .map(|group| {
+            let candidates = ItemSet::common(group.iter().map(Rucksack::items));
+            let mut items = candidates.iter();
+            let priority = match (items.next(), items.next()) {
+                (Some(priority), None) => Some(priority),
+                _ => None,
+            };
+            Badge {
+                from: group[0].line,
+                to: group[group.len() - 1].line,
+                candidates: candidates.to_string(),
+                badge: priority.map(item),
+                priority,
+            }
+        })
+        .collect())
+}
+
+/// The priority of the one kind of item in all of `sets`, which `what` describes for errors
+fn duplicate(sets: impl IntoIterator<Item = ItemSet>, what: impl Fn() -> String) -> Result<u32> {
+    let common = ItemSet::common(sets);
+    let mut items = common.iter();
+    match (items.next(), items.next()) {
+        (Some(priority), None) => Ok(priority),
+        (None, _) => Err(Error::parse(format!("no item is in {}", what()))),
+        _ => Err(Error::parse(format!(
+            "{:?} are all in {}, rather than just one item",
+            common.to_string(),
+            what()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Line;
+
+    use crate::{badges, duplicate, Day3, ItemSet, Solution};
+
+    #[test]
+    fn common_item() {
+        let set = |items: &str| ItemSet::parse(&Line::new(1, items), items).unwrap();
+        let what = String::new;
+        assert_eq!(
+            duplicate([set("vJrwpWtwJgWr"), set("hcsFMMfFFhFp")], what).unwrap(),
+            16
+        );
+        assert_eq!(
+            duplicate([set("abc"), set("xbz"), set("bbq")], what).unwrap(),
+            2
+        );
+        assert!(duplicate([set("abc"), set("xyz")], what).is_err());
+        assert!(duplicate([set("abc"), set("cba")], what).is_err());
+    }
+
+    #[test]
+    fn groups() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\nabde\nabcb\nqaqa\nabAB\nABab";
+        let rucksacks = Day3::parse(input.as_bytes()).unwrap();
+        let badges = badges(&rucksacks, 2).unwrap();
+        let candidates: Vec<_> = badges.iter().map(|x| x.candidates.as_str()).collect();
+        assert_eq!(candidates, vec!["", "a", "abAB"]);
+        assert_eq!((badges[1].from, badges[1].to), (3, 4));
+        assert_eq!((badges[1].badge, badges[1].priority), (Some('a'), Some(1)));
+        assert_eq!(badges[2].badge, None);
+        assert!(crate::badges(&rucksacks, 4).is_err());
+        assert_eq!(crate::badges(&rucksacks, 6).unwrap()[0].candidates, "");
+    }
+}
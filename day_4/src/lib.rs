@@ -0,0 +1,241 @@
+use std::{collections::BTreeSet, fmt, io::BufRead, ops::RangeInclusive};
+
+use aoc_common::{Answer, IntervalSet, Line, Result, Solution};
+use serde::Serialize;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<(SectionRange, SectionRange)>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let mut pairs = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let text = line?;
+            let line = Line::new(i + 1, &text);
+            let (right, left) = line.split_once(&text, ",")?;
+            pairs.push((
+                SectionRange::parse(&line, right)?,
+                SectionRange::parse(&line, left)?,
+            ));
+        }
+        Ok(pairs)
+    }
+
+    /// Total number of pairs where one range contains the other
+    fn part_1(pairs: &Self::Input) -> Result<Answer> {
+        let total_containing = pairs
+            .iter()
+            .filter(|(right, left)| right.contains(left) || left.contains(right))
+            .count();
+        Ok(total_containing.into())
+    }
+
+    /// Total number of pairs whose ranges overlap at all
+    fn part_2(pairs: &Self::Input) -> Result<Answer> {
+        let total_overlaps = pairs
+            .iter()
+            .filter(|(right, left)| right.overlaps(left))
+            .count();
+        Ok(total_overlaps.into())
+    }
+}
+
+/// The sections an elf is assigned to clean, from `start` to `end` inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SectionRange {
+    start: i32,
+    end: i32,
+}
+
+impl SectionRange {
+    /// A range from `start` to `end`, or `None` if it would be backwards
+    pub fn new(start: i32, end: i32) -> Option<Self> {
+        (start <= end).then_some(SectionRange { start, end })
+    }
+
+    /// Parse `val`, a part of `line` like `2-4`
+    fn parse<'a>(line: &Line<'a>, val: &'a str) -> Result<Self> {
+        let (right, left) = line.split_once(val, "-")?;
+        let (start, end) = (line.parse(right)?, line.parse(left)?);
+        SectionRange::new(start, end)
+            .ok_or_else(|| line.error(val, format!("{:?} ends before it starts", val)))
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    /// Whether every section of `other` is in this range too
+    pub fn contains(&self, other: &SectionRange) -> bool {
+        IntervalSet::from(self.range()).contains_range(other.range())
+    }
+
+    /// Whether any section is in both ranges
+    pub fn overlaps(&self, other: &SectionRange) -> bool {
+        IntervalSet::from(self.range()).overlaps(other.range())
+    }
+
+    fn range(&self) -> RangeInclusive<i32> {
+        self.start..=self.end
+    }
+}
+
+impl fmt::Display for SectionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// One elf of the pair on a line of the list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Elf {
+    pub line: usize,
+    /// 1 for the first elf on the line and 2 for the second
+    pub number: usize,
+}
+
+/// How every elf's assignment fits together across the whole list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Coverage {
+    /// From the first section anyone is assigned to the last
+    pub span: Option<SectionRange>,
+    /// The most elves assigned to any one section, and where
+    pub most: usize,
+    pub busiest: Vec<SectionRange>,
+    /// Gaps in the span that nobody is assigned to
+    pub uncovered: Vec<SectionRange>,
+    /// Elves on different lines whose assignments overlap
+    pub overlaps: Vec<(Elf, Elf)>,
+}
+
+/// Find how every assignment in `pairs` fits with every other. Rather than compare each with
+/// each, this sweeps along the sections, keeping track of whose assignment it's in.
+pub fn coverage(pairs: &[(SectionRange, SectionRange)]) -> Coverage {
+    let elves: Vec<(Elf, SectionRange)> = pairs
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &(first, second))| {
+            let elf = |number| Elf {
+                line: i + 1,
+                number,
+            };
+            [(elf(1), first), (elf(2), second)]
+        })
+        .collect();
+
+    // Each elf joins at the start of its range and leaves just after the end, which can be past
+    // the last `i32`. Leaving sorts first, so ranges that only meet end to end don't overlap.
+    let mut events: Vec<(i64, bool, usize)> = elves
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (_, range))| {
+            [
+                (range.start as i64, true, i),
+                (range.end as i64 + 1, false, i),
+            ]
+        })
+        .collect();
+    events.sort();
+
+    let mut active: BTreeSet<usize> = BTreeSet::new();
+    let mut overlaps = Vec::new();
+    // Runs of sections with how many elves are assigned to each
+    let mut runs = Vec::new();
+    for (i, &(pos, joins, elf)) in events.iter().enumerate() {
+        if joins {
+            let (this, _) = elves[elf];
+            for &other in &active {
+                let (other, _) = elves[other];
+                if other.line != this.line {
+                    overlaps.push((other.min(this), other.max(this)));
+                }
+            }
+            active.insert(elf);
+        } else {
+            active.remove(&elf);
+        }
+        match events.get(i + 1) {
+            Some(&(next, _, _)) if next != pos => {
+                let range = SectionRange::new(pos as i32, (next - 1) as i32).unwrap();
+                runs.push((range, active.len()));
+            }
+            _ => (),
+        }
+    }
+    overlaps.sort();
+
+    let most = runs.iter().map(|&(_, elves)| elves).max().unwrap_or(0);
+    let sections = |count: usize| {
+        let mut ranges: Vec<SectionRange> = Vec::new();
+        for &(range, _) in runs.iter().filter(|&&(_, elves)| elves == count) {
+            match ranges.last_mut() {
+                Some(last) if last.end as i64 + 1 == range.start as i64 => last.end = range.end,
+                _ => ranges.push(range),
+            }
+        }
+        ranges
+    };
+    Coverage {
+        span: match (runs.first(), runs.last()) {
+            (Some((first, _)), Some((last, _))) => SectionRange::new(first.start, last.end),
+            _ => None,
+        },
+        most,
+        busiest: if most > 0 { sections(most) } else { Vec::new() },
+        uncovered: sections(0),
+        overlaps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{coverage, Day4, Elf, SectionRange, Solution};
+
+    fn range(start: i32, end: i32) -> SectionRange {
+        SectionRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn ranges() {
+        assert!(range(2, 8).contains(&range(3, 7)));
+        assert!(range(4, 6).contains(&range(6, 6)));
+        assert!(!range(2, 6).contains(&range(4, 8)));
+        assert!(range(5, 7).overlaps(&range(7, 9)));
+        assert!(!range(2, 3).overlaps(&range(4, 5)));
+        assert_eq!(SectionRange::new(3, 2), None);
+
+        assert!(Day4::parse("2-4,8-6".as_bytes()).is_err());
+        assert!(Day4::parse("2-4,6-8".as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn sweep() {
+        let pairs = Day4::parse("2-4,6-8\n3-3,7-9\n12-12,11-12".as_bytes()).unwrap();
+        let coverage = coverage(&pairs);
+        assert_eq!(coverage.span, Some(range(2, 12)));
+        assert_eq!(coverage.most, 2);
+        assert_eq!(
+            coverage.busiest,
+            vec![range(3, 3), range(7, 8), range(12, 12)]
+        );
+        assert_eq!(coverage.uncovered, vec![range(5, 5), range(10, 10)]);
+        // The elves on line 3 overlap each other, but they're a pair already
+        let elf = |line, number| Elf { line, number };
+        assert_eq!(
+            coverage.overlaps,
+            vec![(elf(1, 1), elf(2, 1)), (elf(1, 2), elf(2, 2))]
+        );
+        assert_eq!(super::coverage(&[]).span, None);
+
+        let pairs = Day4::parse("1-2147483647,3-4".as_bytes()).unwrap();
+        let coverage = super::coverage(&pairs);
+        assert_eq!(coverage.busiest, vec![range(3, 4)]);
+        assert_eq!(coverage.span, Some(range(1, i32::MAX)));
+    }
+}
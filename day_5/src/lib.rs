@@ -0,0 +1,240 @@
+use std::io::BufRead;
+
+use aoc_common::{input, Answer, Error, Line, Result, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref INSTRUCTION: Regex = Regex::new(r"^move (\d*) from (\d*) to (\d*)$").unwrap();
+    /// Anything in the row of labels that isn't a space, which should all be labels
+    static ref LABEL: Regex = Regex::new(r"\S+").unwrap();
+}
+
+/// ```text
+///         [G]         [D]     [Q]    
+/// [P]     [T]         [L] [M] [Z]    
+/// [Z] [Z] [C]         [Z] [G] [W]    
+/// [M] [B] [F]         [P] [C] [H] [N]
+/// [T] [S] [R]     [H] [W] [R] [L] [W]
+/// [R] [T] [Q] [Z] [R] [S] [Z] [F] [P]
+/// [C] [N] [H] [R] [N] [H] [D] [J] [Q]
+/// [N] [D] [M] [G] [Z] [F] [W] [S] [S]
+/// 1   2   3   4   5   6   7   8   9
+/// ```
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Cargo;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let lines = input::read_lines(input)?;
+        let blank = lines
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .ok_or_else(|| Error::parse("expected a blank line after the drawing of the stacks"))?;
+        let mut lines = Line::all(&lines);
+        let header: Vec<Line> = lines.by_ref().take(blank).collect();
+        let (labels, drawing) = header
+            .split_last()
+            .ok_or_else(|| Error::parse("expected a drawing of the stacks"))?;
+
+        let mut stacks = vec![Vec::new(); parse_labels(labels)?];
+        // Crates are stacked from the bottom up
+        for row in drawing.iter().rev() {
+            parse_stack(row, &mut stacks)?;
+        }
+
+        let instructions = lines
+            .skip(1)
+            .map(|line| parse_instruction(&line, stacks.len()))
+            .collect::<Result<_>>()?;
+        Ok(Cargo {
+            stacks,
+            instructions,
+        })
+    }
+
+    /// Crates on top of each stack after the CrateMover 9000 moves them one at a time
+    fn part_1(cargo: &Self::Input) -> Result<Answer> {
+        Ok(cargo.rearrange(Crane::CrateMover9000)?.into())
+    }
+
+    /// Crates on top of each stack after the CrateMover 9001 moves them all at once
+    fn part_2(cargo: &Self::Input) -> Result<Answer> {
+        Ok(cargo.rearrange(Crane::CrateMover9001)?.into())
+    }
+}
+
+pub struct Cargo {
+    stacks: Vec<Vec<char>>,
+    instructions: Vec<Instruction>,
+}
+
+impl Cargo {
+    /// The crate on top of each stack after `crane` follows every instruction, or a space for a
+    /// stack left empty
+    fn rearrange(&self, crane: Crane) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for instruction in &self.instructions {
+            apply_instruction(&mut stacks, instruction, &crane)?;
+        }
+        Ok(stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect())
+    }
+}
+
+enum Crane {
+    /// Picks up and moves one crate at a time
+    CrateMover9000,
+    /// Picks up and moves several crates at once, keeping their order
+    CrateMover9001,
+}
+
+#[derive(Debug)]
+struct Instruction {
+    line: usize,
+    count: usize,
+    source: usize,
+    dest: usize,
+}
+
+fn apply_instruction(
+    stacks: &mut [Vec<char>],
+    instruction: &Instruction,
+    crane: &Crane,
+) -> Result<()> {
+    let source = &mut stacks[instruction.source];
+    if instruction.count > source.len() {
+        return Err(Error::parse(format!(
+            "line {} moves {} crates from stack {}, which only has {}",
+            instruction.line,
+            instruction.count,
+            instruction.source + 1,
+            source.len()
+        )));
+    }
+    let mut moving = source.split_off(source.len() - instruction.count);
+    if let Crane::CrateMover9000 = crane {
+        moving.reverse();
+    }
+    stacks[instruction.dest].append(&mut moving);
+    Ok(())
+}
+
+/// Parse an instruction to move crates between `stacks` stacks
+fn parse_instruction(row: &Line, stacks: usize) -> Result<Instruction> {
+    let matches = INSTRUCTION
+        .captures(row.text)
+        .ok_or_else(|| row.invalid("instruction"))?;
+
+    let count = row.parse::<usize>(matches.get(1).unwrap().as_str())?;
+    let source = parse_stack_number(row, matches.get(2).unwrap().as_str(), stacks)?;
+    let dest = parse_stack_number(row, matches.get(3).unwrap().as_str(), stacks)?;
+    Ok(Instruction {
+        line: row.number,
+        count,
+        source,
+        dest,
+    })
+}
+
+/// Stacks are numbered from 1, but indexed from 0
+fn parse_stack_number(row: &Line, number: &str, stacks: usize) -> Result<usize> {
+    match row.parse::<usize>(number)? {
+        0 => Err(row.error(number, "stacks are numbered from 1")),
+        n if n > stacks => Err(row.error(number, format!("there's no stack {}", n))),
+        n => Ok(n - 1),
+    }
+}
+
+/// The number of stacks, from the row of labels under the drawing. They have to count up from 1,
+/// each under its own stack.
+fn parse_labels(row: &Line) -> Result<usize> {
+    let mut stacks = 0;
+    for (i, label) in LABEL.find_iter(row.text).enumerate() {
+        if row.parse::<usize>(label.as_str())? != i + 1 {
+            return Err(row.error(label.as_str(), format!("expected stack {} here", i + 1)));
+        }
+        if label.start() != i * 4 + 1 {
+            return Err(row.error(
+                label.as_str(),
+                format!("stack {} should be labelled in column {}", i + 1, i * 4 + 2),
+            ));
+        }
+        stacks += 1;
+    }
+    if stacks == 0 {
+        return Err(row.error(
+            row.text,
+            "expected a row of stack numbers under the drawing",
+        ));
+    }
+    Ok(stacks)
+}
+
+/// Put the crates in one row of the drawing on top of their stacks. Each stack is 4 characters
+/// wide, `[X]` or blank and then a space, so the crates have to line up with them.
+fn parse_stack(row: &Line, stacks: &mut [Vec<char>]) -> Result<()> {
+    if !row.text.is_ascii() {
+        return Err(row.invalid("row of crates"));
+    }
+    for (idx, start) in (0..row.text.len()).step_by(4).enumerate() {
+        let cell = &row.text[start..(start + 3).min(row.text.len())];
+        match cell.as_bytes() {
+            [b'[', c, b']'] if c.is_ascii_alphabetic() => match stacks.get_mut(idx) {
+                Some(stack) => stack.push(*c as char),
+                None => return Err(row.error(cell, format!("there's no stack {}", idx + 1))),
+            },
+            _ if cell.trim().is_empty() => (),
+            _ => {
+                return Err(row.error(
+                    cell.trim(),
+                    format!("{:?} is not a crate lined up with a stack", cell.trim()),
+                ))
+            }
+        }
+        let gap = &row.text[start + cell.len()..(start + 4).min(row.text.len())];
+        if !gap.trim().is_empty() {
+            return Err(row.error(gap, "expected a space between stacks"));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Crane, Day5, Solution};
+
+    #[test]
+    fn drawing() {
+        let drawing = "[A]     [B]\n[C] [D] [E]\n 1   2   3\n\nmove 1 from 1 to 2";
+        let cargo = Day5::parse(drawing.as_bytes()).unwrap();
+        assert_eq!(
+            cargo.stacks,
+            vec![vec!['C', 'A'], vec!['D'], vec!['E', 'B']]
+        );
+        assert_eq!(cargo.rearrange(Crane::CrateMover9000).unwrap(), "CAB");
+
+        // Empty stacks have nothing on top, and can't have crates moved from them
+        let cargo = Day5::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2".as_bytes()).unwrap();
+        assert_eq!(cargo.rearrange(Crane::CrateMover9001).unwrap(), " A");
+        let cargo = Day5::parse("[A]    \n 1   2 \n\nmove 2 from 1 to 2".as_bytes()).unwrap();
+        assert!(cargo.rearrange(Crane::CrateMover9000).is_err());
+
+        // Crates out of line with the stacks
+        assert!(Day5::parse("[A]  [B]\n 1   2\n\n".as_bytes()).is_err());
+        // More crates than stacks
+        assert!(Day5::parse("[A] [B]\n 1\n\n".as_bytes()).is_err());
+        // Labels that don't count up
+        assert!(Day5::parse("[A] [B]\n 1   3\n\n".as_bytes()).is_err());
+        // Moving from a stack that isn't there
+        assert!(Day5::parse("[A]\n 1\n\nmove 1 from 2 to 1".as_bytes()).is_err());
+        assert!(Day5::parse("[A]\n 1\nmove 1 from 1 to 1".as_bytes()).is_err());
+        // Anything around the instruction
+        assert!(Day5::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2xyz".as_bytes()).is_err());
+        assert!(Day5::parse("[A]    \n 1   2 \n\nplease move 1 from 1 to 2".as_bytes()).is_err());
+    }
+}
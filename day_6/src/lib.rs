@@ -1,48 +1,61 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead},
-};
+use std::{collections::HashSet, io::BufRead};
 
-fn main() {
-    let file = File::open("input.txt").unwrap();
-    for line in io::BufReader::new(file).lines().map(|x| x.unwrap()) {
-        println!(
-            "First packet marker is at position: {}",
-            first_start_of_packet_marker(&line)
-        );
-        println!(
-            "First message marker is at position: {}",
-            first_start_of_message_marker(&line)
-        );
+use aoc_common::{input, Answer, Error, Result, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = String;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        input::read_lines(input)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::parse("expected a datastream"))
+    }
+
+    /// Position of the first start-of-packet marker
+    fn part_1(line: &Self::Input) -> Result<Answer> {
+        first_start_of_packet_marker(line)
+            .map(Answer::from)
+            .ok_or_else(|| Error::parse("no start-of-packet marker in the datastream"))
+    }
+
+    /// Position of the first start-of-message marker
+    fn part_2(line: &Self::Input) -> Result<Answer> {
+        first_start_of_message_marker(line)
+            .map(Answer::from)
+            .ok_or_else(|| Error::parse("no start-of-message marker in the datastream"))
     }
 }
 
-fn first_start_of_message_marker(line: &str) -> usize {
+fn first_start_of_message_marker(line: &str) -> Option<usize> {
     unique_substring_end(line, 14)
 }
 
-fn first_start_of_packet_marker(line: &str) -> usize {
+fn first_start_of_packet_marker(line: &str) -> Option<usize> {
     unique_substring_end(line, 4)
 }
 
-fn unique_substring_end(line: &str, size: usize) -> usize {
+/// Where the first run of `size` different characters ends, if there is one
+fn unique_substring_end(line: &str, size: usize) -> Option<usize> {
     let bytes = line.as_bytes();
     for i in 0..line.len() {
         if i > size - 2 {
             let slice = &bytes[i - (size - 1)..i + 1];
             if unique(slice) {
-                return i + 1;
+                return Some(i + 1);
             }
         }
     }
-    panic!("Cound not find substring of {} unique characters", size)
+    None
 }
 
 fn unique(slice: &[u8]) -> bool {
     let size = slice.len();
     let set: HashSet<&u8> = HashSet::from_iter(slice.iter());
-    return size == set.len();
+    size == set.len()
 }
 
 #[cfg(test)]
@@ -53,19 +66,19 @@ mod tests {
     fn part_1() {
         assert_eq!(
             first_start_of_packet_marker("bvwbjplbgvbhsrlpgdmjqwftvncz"),
-            5
+            Some(5)
         );
         assert_eq!(
             first_start_of_packet_marker("nppdvjthqldpwncqszvftbrmjlhg"),
-            6
+            Some(6)
         );
         assert_eq!(
             first_start_of_packet_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
-            10
+            Some(10)
         );
         assert_eq!(
             first_start_of_packet_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
-            11
+            Some(11)
         );
     }
 
@@ -73,23 +86,24 @@ mod tests {
     fn part_2() {
         assert_eq!(
             first_start_of_message_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
-            19
+            Some(19)
         );
         assert_eq!(
             first_start_of_message_marker("bvwbjplbgvbhsrlpgdmjqwftvncz"),
-            23
+            Some(23)
         );
         assert_eq!(
             first_start_of_message_marker("nppdvjthqldpwncqszvftbrmjlhg"),
-            23
+            Some(23)
         );
         assert_eq!(
             first_start_of_message_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
-            29
+            Some(29)
         );
         assert_eq!(
             first_start_of_message_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
-            26
+            Some(26)
         );
+        assert_eq!(first_start_of_message_marker("abcabcabcabcabcabc"), None);
     }
 }
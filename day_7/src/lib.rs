@@ -1,36 +1,57 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs::File,
-    io::{self, BufRead},
+    io::BufRead,
 };
 
+use aoc_common::{input, Answer, Error, Line, Result, Solution};
 use regex::Regex;
 
 const ROOT: &str = "ROOT";
+const DISK_SPACE: usize = 70000000;
+const UPDATE_SIZE: usize = 30000000;
 
-fn main() {
-    let file = File::open("input.txt").unwrap();
-    let mut dirs = traverse_dirs(file);
-    let dir_sizes = resolve_sizes(&mut dirs);
-
-    // Part 1
-    let sum: usize = dir_sizes.values().filter(|&x| x <= &100000).sum();
-    println!("Sum of sizes for all dirs <= 100,000: {}", sum);
-
-    // Part 2
-    let currently_free = 70000000 - dir_sizes.get(ROOT).unwrap();
-    let need_to_free = 30000000 - currently_free;
-    let mut candidates_to_free: Vec<&usize> =
-        dir_sizes.values().filter(|&x| x >= &need_to_free).collect();
-    candidates_to_free.sort();
-    println!(
-        "Size of smallest dir to free : {}",
-        candidates_to_free.first().unwrap()
-    );
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = HashMap<String, usize>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        let mut dirs = traverse_dirs(input::read_lines(input)?)?;
+        Ok(resolve_sizes(&mut dirs))
+    }
+
+    /// Sum of sizes for all dirs <= 100,000
+    fn part_1(dir_sizes: &Self::Input) -> Result<Answer> {
+        let sum: usize = dir_sizes.values().filter(|&x| x <= &100000).sum();
+        Ok(sum.into())
+    }
+
+    /// Size of smallest dir to free
+    fn part_2(dir_sizes: &Self::Input) -> Result<Answer> {
+        let used = *dir_sizes
+            .get(ROOT)
+            .ok_or_else(|| Error::parse("expected a listing of the root directory"))?;
+        let currently_free = DISK_SPACE.checked_sub(used).ok_or_else(|| {
+            Error::parse(format!(
+                "the files take up {}, more than the whole disk",
+                used
+            ))
+        })?;
+        let need_to_free = UPDATE_SIZE.saturating_sub(currently_free);
+        // The root always frees enough
+        let smallest = dir_sizes.values().filter(|&&x| x >= need_to_free).min();
+        Ok((*smallest.unwrap_or(&used)).into())
+    }
 }
 
 fn resolve_sizes(sizes: &mut HashMap<String, Directory>) -> HashMap<String, usize> {
     let dirs: Vec<String> = sizes.keys().map(|key| key.to_string()).collect();
+    // Directories that were never listed have nothing in them that's known about
+    for (name, dir) in sizes.iter_mut() {
+        dir.children
+            .retain(|child| dirs.contains(&format!("{}/{}", name, child)));
+    }
     let mut complete = HashMap::new();
     while complete.len() < dirs.len() {
         // Go through each known directory and attempt to resolve the sizes of its children.
@@ -38,7 +59,7 @@ fn resolve_sizes(sizes: &mut HashMap<String, Directory>) -> HashMap<String, usiz
             let dir = sizes.get_mut(name).unwrap();
             let children_copy = dir.children.clone();
             // If there are no more children, add it to the complete list
-            if dir.children.len() == 0 {
+            if dir.children.is_empty() {
                 complete.insert(name.clone(), dir.size);
             } else {
                 // Otherwise, check if any of the children are in the complete list and use their sizes
@@ -55,12 +76,12 @@ fn resolve_sizes(sizes: &mut HashMap<String, Directory>) -> HashMap<String, usiz
     complete
 }
 
-fn traverse_dirs(file: File) -> HashMap<String, Directory> {
+fn traverse_dirs(lines: Vec<String>) -> Result<HashMap<String, Directory>> {
     let mut sizes: HashMap<String, Directory> = HashMap::new();
     let mut cur_path: Vec<String> = vec![ROOT.to_string()];
 
-    for line in io::BufReader::new(file).lines().map(|x| x.unwrap()) {
-        match Value::parse(&line) {
+    for line in Line::all(&lines) {
+        match Value::parse(&line)? {
             Value::File(file) => match file {
                 // Add the size of the file to the CWD size
                 FileEntry::File { size } => {
@@ -70,7 +91,7 @@ fn traverse_dirs(file: File) -> HashMap<String, Directory> {
                         sizes.insert(
                             path(&cur_path),
                             Directory {
-                                size: size.clone(),
+                                size,
                                 children: HashSet::new(),
                             },
                         );
@@ -96,7 +117,10 @@ fn traverse_dirs(file: File) -> HashMap<String, Directory> {
                     }
                     // Move up the stack one level
                     CdDirection::Up => {
-                        cur_path.pop().unwrap();
+                        if cur_path.len() == 1 {
+                            return Err(line.error(line.text, "already at the root directory"));
+                        }
+                        cur_path.pop();
                     }
                     // Move down the stack into a directory
                     CdDirection::Into(name) => {
@@ -106,10 +130,10 @@ fn traverse_dirs(file: File) -> HashMap<String, Directory> {
             },
         }
     }
-    sizes
+    Ok(sizes)
 }
 
-fn path(path: &Vec<String>) -> String {
+fn path(path: &[String]) -> String {
     path.join("/")
 }
 
@@ -125,13 +149,13 @@ enum Value {
 }
 
 impl Value {
-    fn parse(line: &str) -> Self {
-        if let Some(action) = Action::parse(&line) {
-            return Value::Action(action);
-        } else if let Some(file) = FileEntry::parse(&line) {
-            return Value::File(file);
+    fn parse(line: &Line) -> Result<Self> {
+        if let Some(action) = Action::parse(line.text) {
+            return Ok(Value::Action(action));
+        } else if let Some(file) = FileEntry::parse(line)? {
+            return Ok(Value::File(file));
         }
-        panic!("Every line should be one of the known variants")
+        Err(line.invalid("command or directory listing"))
     }
 }
 
@@ -142,24 +166,24 @@ enum FileEntry {
 }
 
 impl FileEntry {
-    fn parse(line: &str) -> Option<Self> {
+    fn parse(line: &Line) -> Result<Option<Self>> {
         let re = Regex::new(r"(^\d*) (.*)").unwrap();
-        if let Some(matches) = re.captures(line) {
+        if let Some(matches) = re.captures(line.text) {
             if let Some(size) = matches.get(1) {
-                return Some(FileEntry::File {
-                    size: size.as_str().parse().unwrap(),
-                });
+                return Ok(Some(FileEntry::File {
+                    size: line.parse(size.as_str())?,
+                }));
             }
         }
         let re = Regex::new(r"^dir (\D*)").unwrap();
-        if let Some(matches) = re.captures(line) {
+        if let Some(matches) = re.captures(line.text) {
             if let Some(name) = matches.get(1) {
-                return Some(FileEntry::Dir {
+                return Ok(Some(FileEntry::Dir {
                     name: name.as_str().to_string(),
-                });
+                }));
             }
         }
-        None
+        Ok(None)
     }
 }
 
@@ -193,6 +217,22 @@ impl Action {
                 }
             }
         }
-        return None;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day7, Solution};
+
+    #[test]
+    fn listings() {
+        // Nothing listed, so nothing to free space on
+        let sizes = Day7::parse("".as_bytes()).unwrap();
+        assert!(Day7::part_2(&sizes).is_err());
+        assert!(Day7::parse("$ cd /\n$ cd ..".as_bytes()).is_err());
+        // A directory that's never listed holds nothing
+        let sizes = Day7::parse("$ cd /\n$ ls\ndir a\n100 b".as_bytes()).unwrap();
+        assert_eq!(sizes.get("ROOT"), Some(&100));
     }
 }
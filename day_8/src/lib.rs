@@ -0,0 +1,101 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, Grid, Point, Result, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid<u32>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        Grid::parse(input, "tree height", |x| x.to_digit(10))
+    }
+
+    /// Number of trees visible from outside the grid
+    fn part_1(forest: &Self::Input) -> Result<Answer> {
+        let mut total_visible = 0;
+        for x in 0..forest.width() as i64 {
+            let col: Vec<u32> = forest.col(x).copied().collect();
+            for y in 0..forest.height() as i64 {
+                if visible_in_row(x as usize, forest.row(y)) || visible_in_row(y as usize, &col) {
+                    total_visible += 1;
+                }
+            }
+        }
+        Ok(total_visible.into())
+    }
+
+    /// Highest scenic score of any tree
+    fn part_2(forest: &Self::Input) -> Result<Answer> {
+        let scores = scenic_scores(forest);
+        Ok((*scores.iter().map(|(_, score)| score).max().unwrap()).into())
+    }
+}
+
+/// Every tree's scenic score: how far it can see up, down, left and right multiplied together
+pub fn scenic_scores(forest: &Grid<u32>) -> Grid<usize> {
+    let mut scores = Grid::new(forest.width(), forest.height(), 0);
+    for x in 0..forest.width() as i64 {
+        let col: Vec<u32> = forest.col(x).copied().collect();
+        for y in 0..forest.height() as i64 {
+            let (right, left) = distance_visible(x as usize, forest.row(y));
+            let (up, down) = distance_visible(y as usize, &col);
+            scores[Point::new(x, y)] = right * left * up * down;
+        }
+    }
+    scores
+}
+
+/// Whether or not the tree at this location is visible from the left or right
+fn visible_in_row(idx: usize, line: &[u32]) -> bool {
+    let value = line.get(idx).unwrap();
+    visble(value, &line[0..idx]) || visble(value, &line[idx + 1..])
+}
+
+/// Whether or not the tree at the start or end of this line is visible
+fn visble(value: &u32, line: &[u32]) -> bool {
+    line.iter().all(|x| x < value)
+}
+
+/// The distance (in trees) to the right and left that is visible from this tree
+fn distance_visible(idx: usize, line: &[u32]) -> (usize, usize) {
+    let value = line.get(idx).unwrap();
+
+    let right_side = &line[idx + 1..];
+    let mut left_side = line[..idx].to_vec();
+    left_side.reverse();
+    (
+        distance_right(value, right_side),
+        distance_right(value, &left_side),
+    )
+}
+
+/// The distance to the right that is visible from this tree, including the tree that blocks
+/// the view
+fn distance_right(value: &u32, line: &[u32]) -> usize {
+    match line.iter().position(|x| x >= value) {
+        Some(blocker) => blocker + 1,
+        None => line.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Point, Solution};
+
+    use crate::{distance_right, scenic_scores, Day8};
+
+    #[test]
+    fn viewing_distance() {
+        // The tree that blocks the view is still seen
+        assert_eq!(distance_right(&5, &[3, 5, 3]), 2);
+        assert_eq!(distance_right(&5, &[3, 4, 3]), 3);
+        assert_eq!(distance_right(&5, &[]), 0);
+
+        let forest = Day8::parse("30373\n25512\n65332\n33549\n35390".as_bytes()).unwrap();
+        let scores = scenic_scores(&forest);
+        assert_eq!(scores[Point::new(2, 1)], 4);
+        assert_eq!(scores[Point::new(2, 3)], 8);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashSet, io::BufRead};
+
+use aoc_common::{
+    animate::{Bounds, Render, Simulation},
+    input, Answer, Direction, Line, Point, Result, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Action>;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input> {
+        Line::all(&input::read_lines(input)?)
+            .map(|line| Action::parse(&line))
+            .collect()
+    }
+
+    /// Number of locations visited at least once by the tail of a two knot rope
+    fn part_1(actions: &Self::Input) -> Result<Answer> {
+        Ok(tail_visits(actions, 2).into())
+    }
+
+    /// Number of locations visited at least once by the tail of a ten knot rope
+    fn part_2(actions: &Self::Input) -> Result<Answer> {
+        Ok(tail_visits(actions, 10).into())
+    }
+}
+
+fn tail_visits(actions: &[Action], knots: usize) -> usize {
+    let mut rope = Rope::new(actions, knots);
+    while rope.step() {}
+    rope.visited.len()
+}
+
+/// A rope being pulled around by its head, a step at a time
+pub struct Rope<'a> {
+    actions: &'a [Action],
+    knots: Vec<Point<i64>>,
+    visited: HashSet<Point<i64>>,
+    /// The action being carried out, and how many steps of it are done
+    action: usize,
+    steps: u32,
+}
+
+impl<'a> Rope<'a> {
+    pub fn new(actions: &'a [Action], knots: usize) -> Self {
+        Rope {
+            actions,
+            knots: vec![Point::new(0, 0); knots],
+            visited: HashSet::from([Point::new(0, 0)]),
+            action: 0,
+            steps: 0,
+        }
+    }
+}
+
+impl Simulation for Rope<'_> {
+    /// Move the head one step, with the rest of the knots following it
+    fn step(&mut self) -> bool {
+        while self
+            .actions
+            .get(self.action)
+            .is_some_and(|x| self.steps >= x.distance)
+        {
+            self.action += 1;
+            self.steps = 0;
+        }
+        let Some(action) = self.actions.get(self.action) else {
+            return false;
+        };
+        self.steps += 1;
+
+        self.knots[0] = self.knots[0].step(action.direction);
+        for i in 1..self.knots.len() {
+            let head = self.knots[i - 1];
+            catch_up(&mut self.knots[i], head);
+        }
+        self.visited.insert(self.knots[self.knots.len() - 1]);
+        true
+    }
+}
+
+impl Render for Rope<'_> {
+    fn bounds(&self) -> Bounds {
+        Bounds::around(self.visited.iter().chain(&self.knots).copied()).unwrap()
+    }
+
+    /// Knots are drawn like the puzzle does, from `H` for the head to `T` or `9` for the
+    /// tail, over `#` where the tail has been and `s` where it started
+    fn cell(&self, pos: Point<i64>) -> char {
+        match self.knots.iter().position(|&x| x == pos) {
+            Some(0) => 'H',
+            Some(_) if self.knots.len() == 2 => 'T',
+            Some(i) => char::from_digit(i as u32, 36).unwrap_or('*'),
+            None if pos == Point::new(0, 0) => 's',
+            None if self.visited.contains(&pos) => '#',
+            None => '.',
+        }
+    }
+
+    fn focus(&self) -> Option<Point<i64>> {
+        Some(self.knots[0])
+    }
+
+    fn status(&self) -> String {
+        format!("{} positions visited", self.visited.len())
+    }
+}
+
+/// If the head is ever two steps directly up, down, left, or right from the tail,
+/// the tail must also move one step in that direction so it remains close enough.
+/// Otherwise, if the head and tail aren't touching and aren't in the same row or column,
+/// the tail always moves one step diagonally to keep up.
+fn catch_up(tail: &mut Point<i64>, head: Point<i64>) {
+    if tail.chebyshev(&head) > 1 {
+        *tail += (head - *tail).signum();
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Action {
+    direction: Direction,
+    distance: u32,
+}
+
+impl Action {
+    fn parse(line: &Line) -> Result<Self> {
+        let (direction, distance) = line.split_once(line.text, " ")?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => {
+                return Err(line.error(
+                    direction,
+                    format!("{:?} is not a valid direction", direction),
+                ))
+            }
+        };
+        Ok(Action {
+            direction,
+            distance: line.parse(distance)?,
+        })
+    }
+}